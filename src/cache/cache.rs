@@ -1,6 +1,60 @@
-use std::collections::{HashMap, VecDeque};
-use std::hash::Hash;
-use crate::cache::trait_cache::TraitCache;
+use std::borrow::Borrow;
+use std::collections::{BTreeSet, HashMap};
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, BuildHasherDefault, Hash, Hasher};
+use std::marker::PhantomData;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+use crate::cache::clock::{self, SystemClock};
+use crate::cache::entry::{Entry, OccupiedEntry, VacantEntry};
+use crate::cache::heap_size::HeapSize;
+use crate::cache::iter::{Drain, IntoIter, Iter, IterMut, Keys, Values};
+use crate::cache::list::IndexList;
+use crate::cache::listener::{notify, RemovalCause, RemovalListener};
+use crate::cache::policy::{
+    AdaptiveReplacement, Clock, EvictionPolicy, Fifo, Gdsf, Lfu, Lirs, Lru, Mru, RandomEviction, S3Fifo, SegmentedLru, Sieve,
+    WTinyLfu,
+};
+use crate::cache::trait_cache::{PutResult, TraitCache};
+
+/// Élément stocké dans un emplacement du cache
+///
+/// La clé n'est stockée qu'ici : `cache_content` associe le hash de la clé au premier
+/// emplacement de même hash, les suivants étant chaînés par `next_same_hash`
+pub(super) struct Node<K, V> {
+    pub(super) key: K,
+    pub(super) value: V,
+    hash: u64,
+    weight: u64,
+    expires_at: Option<Instant>,
+    last_access: AtomicU64,
+    refreshed_by_peek: AtomicBool,
+    next_same_hash: Option<usize>,
+}
+
+/// Fonction qui calcule le poids d'un couple clé-valeur
+type Weigher<K, V> = Box<dyn Fn(&K, &V) -> u64 + Send + Sync>;
+
+/// Hasher qui réutilise tel quel un hash déjà calculé
+#[derive(Default)]
+struct HashIdentity(u64);
+
+impl Hasher for HashIdentity {
+    fn finish(&self) -> u64 {
+        self.0
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for &byte in bytes {
+            self.0 = self.0.rotate_left(8) ^ u64::from(byte);
+        }
+    }
+
+    fn write_u64(&mut self, hash: u64) {
+        self.0 = hash;
+    }
+}
 
 /// Structure qui représente un cache LRU
 ///
@@ -8,20 +62,91 @@ use crate::cache::trait_cache::TraitCache;
 /// Lorsque le cache atteint sa capacité maximale, les éléments les plus anciens
 /// sont retirés pour faire de la place aux nouveaux éléments
 ///
-pub struct Cache<K, V>
+/// L'élément retiré est choisi par la politique d'éviction `P` ([`Lru`] par défaut),
+/// voir [`Cache::with_policy`] pour en utiliser une autre
+///
+/// Les éléments sont rangés dans des emplacements (`cache_nodes`) et l'ordre d'utilisation
+/// est une liste doublement chaînée d'emplacements (`cache_order`) :
+/// la lecture, l'insertion, la mise à jour et la suppression sont toutes en O(1)
+///
+/// Chaque clé n'est stockée qu'une seule fois, ni K ni V n'ont besoin d'implémenter `Clone`
+///
+/// Le cache peut aussi être borné par le poids total de ses éléments, voir [`Cache::with_weigher`]
+///
+/// Un élément peut avoir une durée de vie, voir [`Cache::put_with_ttl`] et [`Cache::set_default_ttl`],
+/// et expirer s'il n'est pas utilisé pendant un certain temps, voir [`Cache::set_time_to_idle`]
+///
+/// Le cache est `Send + Sync` lorsque ses clés, ses valeurs et sa politique le sont. La fonction
+/// de poids et l'horloge sont appelées à travers `&Cache` (par `peek` par exemple) et doivent donc
+/// être `Send + Sync`. Le listener n'est appelé qu'à travers `&mut Cache` : il est rangé dans un
+/// `Mutex` qui n'est jamais verrouillé et n'a besoin que d'être `Send`
+///
+pub struct Cache<K, V, P = Lru>
 {
     size: usize,
-    cache_content: HashMap<K, V>,
-    cache_order: VecDeque<K>,
+    hash_builder: RandomState,
+    cache_content: HashMap<u64, usize, BuildHasherDefault<HashIdentity>>,
+    cache_nodes: Vec<Option<Node<K, V>>>,
+    free_slots: Vec<usize>,
+    cache_order: IndexList,
+    removal_listener: Option<RemovalListener<K, V>>,
+    weigher: Option<Weigher<K, V>>,
+    max_weight: Option<u64>,
+    total_weight: u64,
+    default_ttl: Option<Duration>,
+    expirations: BTreeSet<(Instant, usize)>,
+    time_to_idle: Option<Duration>,
+    idle_order: IndexList,
+    idle_epoch: Instant,
+    refresh_on_peek: bool,
+    clock: Box<dyn clock::Clock>,
+    policy: P,
 }
 
+/// Cache LFU : retire l'élément utilisé le moins souvent, voir [`Lfu`]
+pub type LfuCache<K, V> = Cache<K, V, Lfu>;
+
+/// Cache ARC : équilibre seul récence et fréquence, voir [`AdaptiveReplacement`]
+pub type ArcCache<K, V> = Cache<K, V, AdaptiveReplacement>;
+
+/// Cache LRU segmenté : résiste aux parcours de clés vues une seule fois, voir [`SegmentedLru`]
+pub type SlruCache<K, V> = Cache<K, V, SegmentedLru>;
+
+/// Cache W-TinyLFU : n'admet que les éléments plus fréquents que ceux qu'ils remplacent, voir [`WTinyLfu`]
+pub type WTinyLfuCache<K, V> = Cache<K, V, WTinyLfu>;
+
+/// Cache CLOCK : une lecture ne fait que marquer l'élément, voir [`Clock`]
+pub type ClockCache<K, V> = Cache<K, V, Clock>;
+
+/// Cache SIEVE : une lecture ne fait que marquer l'élément, voir [`Sieve`]
+pub type SieveCache<K, V> = Cache<K, V, Sieve>;
+
+/// Cache S3-FIFO : trois files, une lecture ne fait qu'incrémenter un compteur, voir [`S3Fifo`]
+pub type S3FifoCache<K, V> = Cache<K, V, S3Fifo>;
+
+/// Cache LIRS : résiste aux parcours en boucle plus grands que le cache, voir [`Lirs`]
+pub type LirsCache<K, V> = Cache<K, V, Lirs>;
+
+/// Cache FIFO : retire l'élément inséré le plus tôt, voir [`Fifo`]
+pub type FifoCache<K, V> = Cache<K, V, Fifo>;
+
+/// Cache MRU : retire l'élément utilisé le plus récemment, voir [`Mru`]
+pub type MruCache<K, V> = Cache<K, V, Mru>;
+
+/// Cache aléatoire : retire un élément tiré au hasard, voir [`RandomEviction`]
+pub type RandomCache<K, V> = Cache<K, V, RandomEviction>;
+
+/// Cache GDSF : tient compte de la taille et du coût de chaque élément, voir [`Gdsf`]
+pub type GdsfCache<K, V> = Cache<K, V, Gdsf>;
+
 impl<K, V> Cache<K, V>
 where
-    K: Eq + Hash + Clone,
-    V: Clone,
+    K: Eq + Hash,
 {
     /// Créé un cache d'une taille donnée en paramètre
     ///
+    /// Un cache de taille 0 ne conserve aucun élément
+    ///
     /// # Arguments
     /// - `size` : La taille maximale du cache
     ///
@@ -33,62 +158,308 @@ where
     /// let cache : Cache<&str, String> = Cache::new(3);
     /// ```
     pub fn new(size: usize) -> Self {
-        Self {
-            size,
-            cache_content: HashMap::new(),
-            cache_order: VecDeque::new(),
-        }
+        Self::with_policy(size, Lru)
+    }
+
+    /// Créé un cache borné par le poids total de ses éléments plutôt que par leur nombre
+    ///
+    /// Le poids d'un élément est calculé par `weigher` lors de son insertion ou du remplacement
+    /// de sa valeur (modifier la valeur en place ne change pas son poids). Pour faire de la place,
+    /// les éléments les plus anciens sont retirés jusqu'à ce que le nouvel élément tienne.
+    /// Le nombre d'éléments n'est pas limité, `capacity` retourne `usize::MAX`
+    ///
+    /// Un élément plus lourd que `max_weight` n'est jamais conservé : `put` retourne
+    /// [`PutResult::Rejected`] et l'API entry rend la valeur dans une `Err`. Le cache n'est alors
+    /// pas modifié, une clé déjà présente garde son ancienne valeur
+    ///
+    /// # Arguments
+    /// - `max_weight` : Le poids total maximal des éléments du cache
+    /// - `weigher` : La fonction qui calcule le poids d'un couple clé-valeur
+    ///
+    /// # Exemples
+    ///
+    /// ```
+    /// use hashmap_cache::cache::Cache;
+    /// use hashmap_cache::cache::trait_cache::{PutResult, TraitCache};
+    ///
+    /// let mut cache = Cache::with_weigher(10, |_key: &&str, value: &Vec<u8>| value.len() as u64);
+    ///
+    /// cache.put("A", vec![0; 4]); // [A], poids 4
+    /// cache.put("B", vec![0; 4]); // [A,B], poids 8
+    /// cache.put("C", vec![0; 6]); // [B,C] ("A" est supprimé), poids 10
+    ///
+    /// assert_eq!(cache.weight(), 10);
+    /// assert_eq!(cache.put("D", vec![0; 11]), PutResult::Rejected("D", vec![0; 11]));
+    /// assert_eq!(cache.put("C", vec![0; 11]), PutResult::Rejected("C", vec![0; 11]));
+    /// assert_eq!(cache.peek("C"), Some(&vec![0; 6]));
+    /// ```
+    pub fn with_weigher<F>(max_weight: u64, weigher: F) -> Self
+    where
+        F: Fn(&K, &V) -> u64 + Send + Sync + 'static,
+    {
+        let mut cache = Self::new(usize::MAX);
+        cache.weigher = Some(Box::new(weigher));
+        cache.max_weight = Some(max_weight);
+        cache
     }
 }
 
-impl<K, V> TraitCache<K, V> for Cache<K, V>
+impl<K, V> Cache<K, V>
 where
-    K: Eq + Hash + Clone,
-    V: Clone,
+    K: Eq + Hash + HeapSize,
+    V: HeapSize,
 {
-    /// Ajoute une clé et sa valeur associée dans le cache
+    /// Créé un cache borné par une estimation de la mémoire occupée par ses éléments
+    ///
+    /// Chaque élément pèse la mémoire allouée par sa clé et sa valeur (voir [`HeapSize`]) plus
+    /// la gestion interne du cache : son emplacement, sa case dans la table des clés, ses liens
+    /// dans l'ordre du cache et sa place parmi les échéances des durées de vie (comptée même pour
+    /// un élément qui n'expire pas). Les éléments les plus anciens sont retirés pour rester sous
+    /// `max_bytes`, [`Cache::weight`] retourne l'estimation courante en octets
     ///
     /// # Arguments
-    /// - `key` : La clé à insérer
-    /// - `value` : La valeur associée à la clé
+    /// - `max_bytes` : Le nombre maximal d'octets occupés par les éléments du cache
+    ///
     /// # Exemples
     ///
     /// ```
     /// use hashmap_cache::cache::Cache;
     /// use hashmap_cache::cache::trait_cache::TraitCache;
     ///
-    /// let mut cache: Cache<&str, String> = Cache::new(2);
+    /// let mut cache = Cache::with_memory_limit(4096);
     ///
-    /// cache.put("A", String::from("value_a")); // [A]
-    /// cache.put("B", String::from("value_b")); // [A,B]
-    /// cache.put("C", String::from("value_c")); // [B,C] ("A" est supprimé car la taille du cache est de 2)
+    /// for key in 0..100u32 {
+    ///     cache.put(key, vec![0u8; 100]);
+    /// }
+    ///
+    /// assert!(cache.weight() <= 4096);
+    /// assert!(cache.len() < 100);
     /// ```
-    fn put(&mut self, key: K, value: V) {
-        if self.cache_content.contains_key(&key) {
-            // Met à jour la valeur
-            self.cache_content.insert(key.clone(), value);
-            self.move_key_end_cache(&key);
-        } else {
-            if self.cache_order.len() >= self.size {
-                // Enlève la clé la plus ancienne
-                if let Some(cle_supprime) = self.cache_order.pop_front() {
-                    self.cache_content.remove(&cle_supprime);
-                }
+    pub fn with_memory_limit(max_bytes: usize) -> Self {
+        Self::with_weigher(max_bytes as u64, |key: &K, value: &V| {
+            (Self::ENTRY_OVERHEAD + key.heap_size() + value.heap_size()) as u64
+        })
+    }
+}
+
+impl<K, V> Cache<K, V, Gdsf>
+where
+    K: Eq + Hash,
+{
+    /// Ajoute un élément au cache avec sa taille et son coût de recalcul
+    ///
+    /// Si la clé est déjà présente, sa valeur, sa taille et son coût sont remplacés.
+    /// Avec `put`, un élément a une taille et un coût de 1
+    ///
+    /// # Arguments
+    /// - `key` : La clé de l'élément
+    /// - `value` : La valeur de l'élément
+    /// - `size` : La taille de l'élément (0 est compté comme 1)
+    /// - `cost` : Le coût de recalcul de l'élément, positif
+    ///
+    /// # Return
+    /// - `PutResult<K, V>` : Le résultat de l'insertion, comme pour `put`
+    ///
+    /// # Panics
+    /// Si `cost` est négatif ou n'est pas un nombre fini
+    ///
+    /// # Exemples
+    ///
+    /// ```
+    /// use hashmap_cache::cache::GdsfCache;
+    /// use hashmap_cache::cache::trait_cache::{PutResult, TraitCache};
+    ///
+    /// let mut cache = GdsfCache::with_default_policy(2);
+    ///
+    /// cache.put_with_cost("A", 1, 100, 1.0); // priorité 0,01
+    /// cache.put("B", 2); // priorité 1
+    ///
+    /// assert_eq!(cache.put("C", 3), PutResult::Evicted(vec![("A", 1)]));
+    /// ```
+    pub fn put_with_cost(&mut self, key: K, value: V, size: u64, cost: f64) -> PutResult<K, V> {
+        assert!(cost.is_finite() && cost >= 0.0, "le coût doit être un nombre positif");
+        self.policy.set_pending(Some((size.max(1), cost)));
+        let result = self.put(key, value);
+        self.policy.set_pending(None);
+        result
+    }
+}
+
+impl<K, V, P> Cache<K, V, P>
+where
+    K: Eq + Hash,
+    P: EvictionPolicy,
+{
+    /// Créé un cache d'une taille donnée en paramètre qui utilise la politique d'éviction `policy`
+    ///
+    /// # Arguments
+    /// - `size` : La taille maximale du cache
+    /// - `policy` : La politique qui choisit l'élément à retirer lorsque le cache est plein
+    ///
+    /// # Exemples
+    ///
+    /// ```
+    /// use hashmap_cache::cache::Cache;
+    /// use hashmap_cache::cache::policy::Lru;
+    ///
+    /// let cache: Cache<&str, String, Lru> = Cache::with_policy(3, Lru);
+    /// ```
+    pub fn with_policy(size: usize, mut policy: P) -> Self {
+        policy.on_capacity(size);
+        Self {
+            size,
+            hash_builder: RandomState::new(),
+            cache_content: HashMap::default(),
+            cache_nodes: Vec::new(),
+            free_slots: Vec::new(),
+            cache_order: IndexList::new(),
+            removal_listener: None,
+            weigher: None,
+            max_weight: None,
+            total_weight: 0,
+            default_ttl: None,
+            expirations: BTreeSet::new(),
+            time_to_idle: None,
+            idle_order: IndexList::new(),
+            idle_epoch: Instant::now(),
+            refresh_on_peek: false,
+            clock: Box::new(SystemClock),
+            policy,
+        }
+    }
+
+    /// Créé un cache d'une taille donnée en paramètre avec la politique d'éviction `P` par défaut
+    ///
+    /// Permets de créer les caches des autres politiques comme avec [`Cache::new`]
+    ///
+    /// # Arguments
+    /// - `size` : La taille maximale du cache
+    ///
+    /// # Exemples
+    ///
+    /// ```
+    /// use hashmap_cache::cache::LfuCache;
+    /// use hashmap_cache::cache::trait_cache::TraitCache;
+    ///
+    /// let mut cache = LfuCache::with_default_policy(2);
+    ///
+    /// cache.put("A", 1);
+    /// assert_eq!(cache.get("A"), Some(&1));
+    /// ```
+    pub fn with_default_policy(size: usize) -> Self
+    where
+        P: Default,
+    {
+        Self::with_policy(size, P::default())
+    }
+
+    /// Retourne l'entrée de la clé K pour la lire, la modifier ou l'insérer en une seule recherche
+    ///
+    /// Si la clé est présente, elle est placée à la fin du cache (élément le plus récent).
+    /// Une clé expirée est retirée et son entrée est vide
+    ///
+    /// # Arguments
+    /// - `key` : La clé de l'entrée
+    ///
+    /// # Exemples
+    ///
+    /// ```
+    /// use hashmap_cache::cache::{Cache, Entry};
+    ///
+    /// let mut cache = Cache::new(2);
+    ///
+    /// cache.entry("A").or_insert(String::from("value_a")).unwrap(); // [A]
+    ///
+    /// match cache.entry("A") {
+    ///     Entry::Occupied(mut entry) => { entry.insert(String::from("value_a2")).unwrap(); }
+    ///     Entry::Vacant(_) => unreachable!(),
+    /// }
+    /// ```
+    pub fn entry(&mut self, key: K) -> Entry<'_, K, V, P> {
+        let hash = self.hash_builder.hash_one(&key);
+        match self.find_live(hash, &key) {
+            Some(slot) => {
+                self.promote(slot);
+                Entry::Occupied(OccupiedEntry { cache: self, slot })
             }
-            // Ajoute la nouvelle pair de clé-valeur
-            self.cache_order.push_back(key.clone());
-            self.cache_content.insert(key, value);
+            None => Entry::Vacant(VacantEntry { cache: self, key, hash }),
         }
     }
 
-    /// Retourne la valeur V de la clé K
-    /// et place l'élément à la fin du cache (élément le plus récent)
+    /// Retourne la politique d'éviction du cache
+    pub fn policy(&self) -> &P {
+        &self.policy
+    }
+
+    /// Retourne la valeur de la clé K, en la calculant avec `f` et en l'insérant si elle est absente
+    ///
+    /// L'élément est placé à la fin du cache (élément le plus récent)
+    /// et l'élément le plus ancien est retiré si le cache est plein
     ///
     /// # Arguments
     /// - `key` : La clé dont on veut obtenir la valeur
+    /// - `f` : La fonction qui calcule la valeur si la clé est absente
     ///
     /// # Return
-    /// - `Option<&V>` : La valeur associée si la clé existe sinon `None`
+    /// - `Result<&V, V>` : La valeur du cache, ou la valeur calculée si le cache ne peut pas
+    ///   la conserver (voir [`VacantEntry::insert`])
+    ///
+    /// # Exemples
+    ///
+    /// ```
+    /// use hashmap_cache::cache::Cache;
+    ///
+    /// let mut cache = Cache::new(2);
+    ///
+    /// assert_eq!(cache.get_or_insert_with("A", || String::from("value_a")).unwrap(), "value_a"); // [A]
+    /// assert_eq!(cache.get_or_insert_with("A", || unreachable!()).unwrap(), "value_a"); // [A]
+    ///
+    /// cache.set_capacity(0); // []
+    /// assert_eq!(cache.get_or_insert_with("A", || String::from("value_a")), Err(String::from("value_a")));
+    /// ```
+    pub fn get_or_insert_with<F>(&mut self, key: K, f: F) -> Result<&V, V>
+    where
+        F: FnOnce() -> V,
+    {
+        self.entry(key).or_insert_with(f).map(|value| &*value)
+    }
+
+    /// Retourne la valeur de la clé K, en la calculant avec `f` et en l'insérant si elle est absente
+    ///
+    /// Si `f` échoue, l'erreur est retournée et le cache n'est pas modifié
+    ///
+    /// # Arguments
+    /// - `key` : La clé dont on veut obtenir la valeur
+    /// - `f` : La fonction faillible qui calcule la valeur si la clé est absente
+    ///
+    /// # Return
+    /// - `Result<Result<&V, V>, E>` : L'erreur retournée par `f`, sinon la valeur du cache
+    ///   ou la valeur calculée si le cache ne peut pas la conserver (voir [`VacantEntry::insert`])
+    ///
+    /// # Exemples
+    ///
+    /// ```
+    /// use hashmap_cache::cache::Cache;
+    ///
+    /// let mut cache: Cache<&str, i32> = Cache::new(2);
+    ///
+    /// assert_eq!(cache.get_or_try_insert_with("A", || "12".parse()), Ok(Ok(&12))); // [A]
+    /// assert!(cache.get_or_try_insert_with("B", || "x".parse::<i32>()).is_err()); // [A]
+    /// assert_eq!(cache.get_or_insert_with("B", || 0), Ok(&0)); // [A,B]
+    /// ```
+    pub fn get_or_try_insert_with<F, E>(&mut self, key: K, f: F) -> Result<Result<&V, V>, E>
+    where
+        F: FnOnce() -> Result<V, E>,
+    {
+        match self.entry(key) {
+            Entry::Occupied(entry) => Ok(Ok(entry.into_mut())),
+            Entry::Vacant(entry) => Ok(entry.insert(f()?).map(|value| &*value)),
+        }
+    }
+
+    /// Retourne un itérateur sur les couples clé-valeur, du plus récent au plus ancien
+    ///
+    /// L'ordre du cache n'est pas modifié. `.rev()` parcourt le cache du plus ancien au plus récent
     ///
     /// # Exemples
     ///
@@ -98,26 +469,127 @@ where
     ///
     /// let mut cache = Cache::new(3);
     ///
-    /// cache.put("A", String::from("value_a")); // [A]
-    /// cache.put("B", String::from("value_b")); // [A,B]
-    /// cache.put("C", String::from("value_c")); // [A,B,C]
+    /// cache.put("A", 1); // [A]
+    /// cache.put("B", 2); // [A,B]
+    /// cache.put("C", 3); // [A,B,C]
     ///
-    /// assert_eq!(cache.get("A"), Some(&String::from("value_a"))); // [B,C,A]
-    /// assert_eq!(cache.get("X"), None); // "X" n'est pas dans le cache
+    /// let recents: Vec<_> = cache.iter().collect();
+    /// assert_eq!(recents, [(&"C", &3), (&"B", &2), (&"A", &1)]);
+    ///
+    /// let anciens: Vec<_> = cache.iter().rev().map(|(k, _)| *k).collect();
+    /// assert_eq!(anciens, ["A", "B", "C"]);
     /// ```
-    fn get(&mut self, key: K) -> Option<&V> {
-        if self.cache_content.contains_key(&key) {
-            self.move_key_end_cache(&key);
-            self.cache_content.get(&key)
-        } else {
-            None
+    pub fn iter(&self) -> Iter<'_, K, V> {
+        Iter {
+            nodes: &self.cache_nodes,
+            order: self.cache_order.iter(),
         }
     }
 
-    /// Déplace une clé à la fin du cache
+    /// Retourne un itérateur sur les couples clé-valeur modifiables, du plus récent au plus ancien
+    ///
+    /// L'ordre du cache n'est pas modifié
+    ///
+    /// # Exemples
+    ///
+    /// ```
+    /// use hashmap_cache::cache::Cache;
+    /// use hashmap_cache::cache::trait_cache::TraitCache;
+    ///
+    /// let mut cache = Cache::new(2);
+    ///
+    /// cache.put("A", 1); // [A]
+    /// cache.put("B", 2); // [A,B]
+    ///
+    /// for (_, value) in cache.iter_mut() {
+    ///     *value *= 10;
+    /// }
+    ///
+    /// assert_eq!(cache.peek("A"), Some(&10));
+    /// ```
+    pub fn iter_mut(&mut self) -> IterMut<'_, K, V> {
+        IterMut {
+            nodes: self.cache_nodes.as_mut_ptr(),
+            order: self.cache_order.iter(),
+            marker: PhantomData,
+        }
+    }
+
+    /// Retourne un itérateur sur les clés, du plus récent au plus ancien
+    ///
+    /// L'ordre du cache n'est pas modifié
+    ///
+    /// # Exemples
+    ///
+    /// ```
+    /// use hashmap_cache::cache::Cache;
+    /// use hashmap_cache::cache::trait_cache::TraitCache;
+    ///
+    /// let mut cache = Cache::new(2);
+    ///
+    /// cache.put("A", 1); // [A]
+    /// cache.put("B", 2); // [A,B]
+    ///
+    /// assert_eq!(cache.keys().collect::<Vec<_>>(), [&"B", &"A"]);
+    /// ```
+    pub fn keys(&self) -> Keys<'_, K, V> {
+        Keys { inner: self.iter() }
+    }
+
+    /// Retourne un itérateur sur les valeurs, du plus récent au plus ancien
+    ///
+    /// L'ordre du cache n'est pas modifié
+    ///
+    /// # Exemples
+    ///
+    /// ```
+    /// use hashmap_cache::cache::Cache;
+    /// use hashmap_cache::cache::trait_cache::TraitCache;
+    ///
+    /// let mut cache = Cache::new(2);
+    ///
+    /// cache.put("A", 1); // [A]
+    /// cache.put("B", 2); // [A,B]
+    ///
+    /// assert_eq!(cache.values().collect::<Vec<_>>(), [&2, &1]);
+    /// ```
+    pub fn values(&self) -> Values<'_, K, V> {
+        Values { inner: self.iter() }
+    }
+
+    /// Vide le cache et retourne un itérateur sur les couples clé-valeur retirés,
+    /// du plus récent au plus ancien
+    ///
+    /// # Exemples
+    ///
+    /// ```
+    /// use hashmap_cache::cache::Cache;
+    /// use hashmap_cache::cache::trait_cache::TraitCache;
+    ///
+    /// let mut cache = Cache::new(2);
+    ///
+    /// cache.put("A", 1); // [A]
+    /// cache.put("B", 2); // [A,B]
+    ///
+    /// assert_eq!(cache.drain().collect::<Vec<_>>(), [("B", 2), ("A", 1)]);
+    /// assert!(cache.is_empty());
+    /// ```
+    pub fn drain(&mut self) -> Drain<'_, K, V, P> {
+        Drain { cache: self }
+    }
+
+    /// Change la taille maximale du cache
+    ///
+    /// Si la nouvelle taille est plus petite que le nombre d'éléments présents, les éléments expirés
+    /// puis les éléments les plus anciens sont retirés. Avec une taille de 0 le cache est vidé
+    /// et n'accepte plus aucun élément
     ///
     /// # Arguments
-    /// - `key` : La clé que l'on veut déplacer
+    /// - `size` : La nouvelle taille maximale du cache
+    ///
+    /// # Return
+    /// - `Vec<(K, V)>` : Les couples clé-valeur retirés, les éléments expirés en premier
+    ///   puis les autres du plus ancien au plus récent
     ///
     /// # Exemples
     ///
@@ -127,14 +599,1169 @@ where
     ///
     /// let mut cache = Cache::new(3);
     ///
-    /// cache.put("A", String::from("value_a")); // [A]
-    /// cache.put("B", String::from("value_b")); // [A,B]
-    /// cache.put("C", String::from("value_c")); // [A,B,C]
+    /// cache.put("A", 1); // [A]
+    /// cache.put("B", 2); // [A,B]
+    /// cache.put("C", 3); // [A,B,C]
     ///
-    /// cache.move_key_end_cache(&"A"); // [B,C,A]
+    /// assert_eq!(cache.set_capacity(1), [("A", 1), ("B", 2)]); // [C]
+    /// assert_eq!(cache.capacity(), 1);
+    ///
+    /// assert!(cache.set_capacity(5).is_empty()); // [C]
+    /// cache.put("D", 4); // [C,D]
+    /// assert_eq!(cache.len(), 2);
+    /// ```
+    pub fn set_capacity(&mut self, size: usize) -> Vec<(K, V)> {
+        let mut evicted = Vec::new();
+        if self.cache_len() > size {
+            self.evict_expired(|pair| evicted.push(pair));
+        }
+        self.size = size;
+        self.policy.on_capacity(size);
+        while self.cache_len() > self.size {
+            let Some(slot) = self.policy.choose_victim(&self.cache_order) else {
+                break;
+            };
+            evicted.push(self.evict_slot(slot, RemovalCause::Capacity));
+        }
+        evicted
+    }
+
+    /// Enregistre une fonction appelée pour chaque élément qui quitte le cache,
+    /// avec la raison de son retrait
+    ///
+    /// Le listener est appelé par `put` (élément remplacé ou retiré pour faire de la place),
+    /// `remove`, `set_capacity` et `clear`. Les éléments rendus par `drain` et `into_iter`
+    /// sont confiés à l'appelant et ne sont pas signalés
+    ///
+    /// # Arguments
+    /// - `listener` : La fonction appelée avec la clé, la valeur et la raison du retrait
+    ///
+    /// # Exemples
+    ///
+    /// ```
+    /// use std::sync::{Arc, Mutex};
+    /// use hashmap_cache::cache::{Cache, RemovalCause};
+    /// use hashmap_cache::cache::trait_cache::TraitCache;
+    ///
+    /// let removed = Arc::new(Mutex::new(Vec::new()));
+    /// let mut cache = Cache::new(1);
+    ///
+    /// let log = Arc::clone(&removed);
+    /// cache.set_removal_listener(move |key: &&str, value: &i32, cause| log.lock().unwrap().push((*key, *value, cause)));
+    ///
+    /// cache.put("A", 1); // [A]
+    /// cache.put("A", 2); // [A]
+    /// cache.put("B", 3); // [B]
+    /// cache.remove("B"); // []
+    ///
+    /// assert_eq!(*removed.lock().unwrap(), [
+    ///     ("A", 1, RemovalCause::Replaced),
+    ///     ("A", 2, RemovalCause::Capacity),
+    ///     ("B", 3, RemovalCause::Explicit),
+    /// ]);
     /// ```
-    fn move_key_end_cache(&mut self, key: &K) {
-        self.cache_order.retain(|k| k != key);
-        self.cache_order.push_back(key.clone());
+    pub fn set_removal_listener<F>(&mut self, listener: F)
+    where
+        F: FnMut(&K, &V, RemovalCause) + Send + 'static,
+    {
+        self.removal_listener = Some(Mutex::new(Box::new(listener)));
     }
-}
\ No newline at end of file
+
+    /// Retire tous les éléments du cache
+    ///
+    /// Le listener est appelé pour chaque élément, du plus ancien au plus récent
+    ///
+    /// # Exemples
+    ///
+    /// ```
+    /// use hashmap_cache::cache::Cache;
+    /// use hashmap_cache::cache::trait_cache::TraitCache;
+    ///
+    /// let mut cache = Cache::new(2);
+    ///
+    /// cache.put("A", 1); // [A]
+    /// cache.put("B", 2); // [A,B]
+    /// cache.clear(); // []
+    ///
+    /// assert!(cache.is_empty());
+    /// assert_eq!(cache.capacity(), 2);
+    /// ```
+    pub fn clear(&mut self) {
+        while let Some(slot) = self.cache_order.front() {
+            self.evict_slot(slot, RemovalCause::Cleared);
+        }
+    }
+
+    /// Retourne le poids total des éléments du cache
+    ///
+    /// Sans fonction de poids (voir [`Cache::with_weigher`]), chaque élément pèse 1
+    ///
+    /// # Exemples
+    ///
+    /// ```
+    /// use hashmap_cache::cache::Cache;
+    /// use hashmap_cache::cache::trait_cache::TraitCache;
+    ///
+    /// let mut cache = Cache::with_weigher(100, |key: &&str, _value: &i32| key.len() as u64);
+    ///
+    /// cache.put("AAA", 1);
+    /// cache.put("BB", 2);
+    ///
+    /// assert_eq!(cache.weight(), 5);
+    /// ```
+    pub fn weight(&self) -> u64 {
+        self.total_weight
+    }
+
+    /// Retourne le poids total maximal du cache, `None` si le cache n'est borné que par son nombre d'éléments
+    pub fn max_weight(&self) -> Option<u64> {
+        self.max_weight
+    }
+
+    /// Ajoute un élément au cache qui expire après la durée `ttl`
+    ///
+    /// Un élément expiré est absent pour `get`, `peek`, `contains`... et il est retiré dès qu'une
+    /// méthode qui modifie le cache le rencontre. Pour faire de la place, les éléments expirés sont
+    /// retirés avant les éléments choisis par la politique d'éviction. Si la clé est déjà présente,
+    /// sa valeur et sa durée de vie sont remplacées. Une durée trop grande pour être ajoutée
+    /// à l'instant présent (`Duration::MAX` par exemple) revient à ne jamais expirer
+    ///
+    /// # Arguments
+    /// - `key` : La clé de l'élément
+    /// - `value` : La valeur de l'élément
+    /// - `ttl` : La durée de vie de l'élément à partir de maintenant
+    ///
+    /// # Return
+    /// - `PutResult<K, V>` : Le résultat de l'insertion, comme pour `put`
+    ///
+    /// # Exemples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use hashmap_cache::cache::{Cache, MockClock};
+    /// use hashmap_cache::cache::trait_cache::TraitCache;
+    ///
+    /// let clock = MockClock::new();
+    /// let mut cache = Cache::new(2);
+    /// cache.set_clock(clock.clone());
+    ///
+    /// cache.put_with_ttl("token", "abc", Duration::from_secs(60)); // [token]
+    /// cache.put_with_ttl("dns", "1.2.3.4", Duration::from_secs(5)); // [token,dns]
+    ///
+    /// clock.advance(Duration::from_secs(5));
+    /// assert_eq!(cache.get("token"), Some(&"abc"));
+    /// assert_eq!(cache.get("dns"), None); // [token] ("dns" a expiré)
+    /// assert_eq!(cache.len(), 1);
+    /// ```
+    pub fn put_with_ttl(&mut self, key: K, value: V, ttl: Duration) -> PutResult<K, V> {
+        self.put_expiring(key, value, Some(ttl))
+    }
+
+    /// Change la durée de vie donnée aux éléments ajoutés sans durée de vie explicite
+    ///
+    /// Les éléments déjà présents gardent leur durée de vie
+    ///
+    /// # Arguments
+    /// - `ttl` : La durée de vie par défaut, `None` pour que les éléments n'expirent pas
+    ///
+    /// # Exemples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use hashmap_cache::cache::Cache;
+    /// use hashmap_cache::cache::trait_cache::TraitCache;
+    ///
+    /// let mut cache = Cache::new(2);
+    /// cache.set_default_ttl(Some(Duration::ZERO));
+    ///
+    /// cache.put("A", 1); // [A] ("A" est déjà expiré)
+    /// assert!(!cache.contains("A"));
+    /// ```
+    pub fn set_default_ttl(&mut self, ttl: Option<Duration>) {
+        self.default_ttl = ttl;
+    }
+
+    /// Retourne la durée de vie donnée aux éléments ajoutés sans durée de vie explicite
+    pub fn default_ttl(&self) -> Option<Duration> {
+        self.default_ttl
+    }
+
+    /// Fait expirer les éléments qui ne sont pas utilisés pendant la durée `time_to_idle`
+    ///
+    /// Chaque utilisation d'un élément (`get`, `get_mut`, `entry`, `put`...) repousse son expiration.
+    /// `peek` et `peek_mut` ne la repoussent que si [`Cache::set_refresh_on_peek`] l'a demandé.
+    /// L'expiration est vérifiée dans l'ordre des utilisations, à partir des éléments inutilisés
+    /// depuis le plus longtemps et jusqu'au premier élément encore utilisé. Si la politique place
+    /// les éléments utilisés à la fin du cache (voir [`EvictionPolicy::PROMOTE_ON_ACCESS`]), l'ordre
+    /// du cache est déjà celui des utilisations. Sinon (FIFO, CLOCK...) le cache le tient à part,
+    /// sans modifier son ordre ni la politique d'éviction
+    ///
+    /// # Arguments
+    /// - `time_to_idle` : La durée d'inutilisation après laquelle un élément expire,
+    ///   `None` pour que les éléments n'expirent pas faute d'utilisation
+    ///
+    /// # Exemples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use hashmap_cache::cache::{Cache, MockClock};
+    /// use hashmap_cache::cache::trait_cache::TraitCache;
+    ///
+    /// let clock = MockClock::new();
+    /// let mut cache = Cache::new(2);
+    /// cache.set_clock(clock.clone());
+    /// cache.set_time_to_idle(Some(Duration::from_secs(600)));
+    ///
+    /// cache.put("session", 1); // [session]
+    /// clock.advance(Duration::from_secs(500));
+    /// assert_eq!(cache.get("session"), Some(&1)); // l'expiration est repoussée de 10 minutes
+    ///
+    /// clock.advance(Duration::from_secs(600));
+    /// assert_eq!(cache.get("session"), None); // []
+    /// ```
+    pub fn set_time_to_idle(&mut self, time_to_idle: Option<Duration>) {
+        if self.time_to_idle.is_none() && time_to_idle.is_some() {
+            // Les éléments présents commencent leur période d'inutilisation maintenant
+            self.idle_epoch = self.now();
+            for slot in self.cache_order.iter() {
+                let node = self.node(slot);
+                node.last_access.store(0, Ordering::Relaxed);
+                node.refreshed_by_peek.store(false, Ordering::Relaxed);
+                if !P::PROMOTE_ON_ACCESS {
+                    self.idle_order.push_back(slot);
+                }
+            }
+        } else if time_to_idle.is_none() {
+            self.idle_order = IndexList::new();
+        }
+        self.time_to_idle = time_to_idle;
+    }
+
+    /// Retourne la durée d'inutilisation après laquelle un élément expire
+    pub fn time_to_idle(&self) -> Option<Duration> {
+        self.time_to_idle
+    }
+
+    /// Indique si `peek` et `peek_mut` repoussent l'expiration d'un élément inutilisé
+    ///
+    /// Désactivé par défaut : consulter un élément sans modifier l'ordre du cache ne compte pas
+    /// comme une utilisation
+    ///
+    /// # Arguments
+    /// - `refresh_on_peek` : `true` pour que `peek` et `peek_mut` comptent comme une utilisation
+    ///
+    /// # Exemples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use hashmap_cache::cache::Cache;
+    /// use hashmap_cache::cache::trait_cache::TraitCache;
+    ///
+    /// let mut cache = Cache::new(2);
+    /// cache.set_time_to_idle(Some(Duration::from_secs(600)));
+    /// cache.set_refresh_on_peek(true);
+    ///
+    /// cache.put("A", 1); // [A]
+    /// assert_eq!(cache.peek("A"), Some(&1)); // l'expiration de "A" est repoussée
+    /// ```
+    pub fn set_refresh_on_peek(&mut self, refresh_on_peek: bool) {
+        self.refresh_on_peek = refresh_on_peek;
+    }
+
+    /// Change l'horloge utilisée pour les durées de vie et l'expiration faute d'utilisation
+    ///
+    /// Le cache utilise [`SystemClock`] par défaut. L'horloge doit être choisie avant d'ajouter
+    /// des éléments qui ont une durée de vie : leurs échéances ont été calculées avec l'ancienne horloge.
+    /// Les éléments présents commencent leur période d'inutilisation à l'instant de la nouvelle horloge
+    ///
+    /// # Arguments
+    /// - `clock` : L'horloge du cache
+    ///
+    /// # Exemples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use hashmap_cache::cache::{Cache, MockClock};
+    /// use hashmap_cache::cache::trait_cache::TraitCache;
+    ///
+    /// let clock = MockClock::new();
+    /// let mut cache = Cache::new(2);
+    /// cache.set_clock(clock.clone());
+    ///
+    /// cache.put_with_ttl("A", 1, Duration::from_secs(10)); // [A]
+    /// clock.advance(Duration::from_secs(9));
+    /// assert_eq!(cache.get("A"), Some(&1));
+    ///
+    /// clock.advance(Duration::from_secs(1));
+    /// assert_eq!(cache.get("A"), None); // []
+    /// ```
+    pub fn set_clock<C>(&mut self, clock: C)
+    where
+        C: clock::Clock + 'static,
+    {
+        self.clock = Box::new(clock);
+        self.idle_epoch = self.now();
+        for slot in self.cache_order.iter() {
+            self.node(slot).last_access.store(0, Ordering::Relaxed);
+        }
+    }
+
+    /// Retire tous les éléments expirés du cache
+    ///
+    /// Les éléments expirés sont absents pour les recherches mais restent comptés par `len`
+    /// et parcourus par les itérateurs tant qu'ils n'ont pas été retirés
+    ///
+    /// # Return
+    /// - `usize` : Le nombre d'éléments retirés
+    ///
+    /// # Exemples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use hashmap_cache::cache::Cache;
+    /// use hashmap_cache::cache::trait_cache::TraitCache;
+    ///
+    /// let mut cache = Cache::new(3);
+    ///
+    /// cache.put_with_ttl("A", 1, Duration::ZERO); // [A]
+    /// cache.put("B", 2); // [A,B]
+    ///
+    /// assert_eq!(cache.remove_expired(), 1); // [B]
+    /// assert_eq!(cache.len(), 1);
+    /// ```
+    pub fn remove_expired(&mut self) -> usize {
+        let mut removed = 0;
+        self.evict_expired(|_| removed += 1);
+        removed
+    }
+
+    /// Retourne une estimation du nombre d'octets occupés par le cache
+    ///
+    /// Compte la structure du cache, la mémoire réservée pour ses emplacements, sa table des clés,
+    /// son ordre et les échéances des durées de vie, ainsi que la mémoire allouée par les clés
+    /// et les valeurs. La mémoire propre
+    /// à la politique d'éviction n'est pas comptée. Le calcul parcourt tous les éléments,
+    /// [`Cache::weight`] donne en O(1) l'estimation d'un cache créé avec [`Cache::with_memory_limit`]
+    ///
+    /// # Exemples
+    ///
+    /// ```
+    /// use hashmap_cache::cache::Cache;
+    /// use hashmap_cache::cache::trait_cache::TraitCache;
+    ///
+    /// let mut cache = Cache::new(10);
+    /// let empty = cache.memory_usage();
+    ///
+    /// cache.put(1u32, String::from("value"));
+    ///
+    /// assert!(cache.memory_usage() >= empty + 5);
+    /// ```
+    pub fn memory_usage(&self) -> usize
+    where
+        K: HeapSize,
+        V: HeapSize,
+    {
+        std::mem::size_of::<Self>()
+            + self.cache_nodes.capacity() * std::mem::size_of::<Option<Node<K, V>>>()
+            + self.free_slots.capacity() * std::mem::size_of::<usize>()
+            + self.cache_content.capacity() * (std::mem::size_of::<(u64, usize)>() + 1)
+            + self.cache_order.heap_size()
+            + self.idle_order.heap_size()
+            + self.expirations.len() * Self::EXPIRATION_SIZE
+            + self.iter().map(|(key, value)| key.heap_size() + value.heap_size()).sum::<usize>()
+    }
+
+    /// Octets occupés par l'échéance d'un élément dans `expirations`
+    const EXPIRATION_SIZE: usize = std::mem::size_of::<(Instant, usize)>();
+
+    /// Octets de gestion interne comptés pour chaque élément : son emplacement,
+    /// sa case dans `cache_content`, ses liens dans `cache_order` et son échéance dans `expirations`
+    const ENTRY_OVERHEAD: usize = std::mem::size_of::<Option<Node<K, V>>>()
+        + std::mem::size_of::<(u64, usize)>()
+        + 1
+        + IndexList::LINK_SIZE
+        + Self::EXPIRATION_SIZE;
+
+    /// Retire les éléments expirés du cache, en prévenant le listener,
+    /// et passe chaque couple clé-valeur retiré à `on_evicted`
+    fn evict_expired<F>(&mut self, mut on_evicted: F)
+    where
+        F: FnMut((K, V)),
+    {
+        if self.expirations.is_empty() && self.time_to_idle.is_none() {
+            return;
+        }
+        let now = self.now();
+        while let Some(&(expires_at, slot)) = self.expirations.first() {
+            if expires_at > now {
+                break;
+            }
+            on_evicted(self.evict_slot(slot, RemovalCause::Expired));
+        }
+        if self.time_to_idle.is_some() {
+            // Les éléments inutilisés depuis le plus longtemps sont en tête de l'ordre des utilisations
+            let mut current = self.idle_order().front();
+            while let Some(slot) = current {
+                current = self.idle_order().next(slot);
+                if self.idle_deadline(slot).is_some_and(|idle_deadline| idle_deadline <= now) {
+                    on_evicted(self.evict_slot(slot, RemovalCause::Expired));
+                } else if !self.node(slot).refreshed_by_peek.load(Ordering::Relaxed) {
+                    break;
+                }
+                // Un élément utilisé par `peek` n'a pas été déplacé : les suivants sont vérifiés
+            }
+        }
+    }
+
+    /// Retourne l'ordre des utilisations, du plus ancien au plus récent
+    ///
+    /// C'est l'ordre du cache si la politique y déplace les éléments utilisés
+    fn idle_order(&self) -> &IndexList {
+        if P::PROMOTE_ON_ACCESS {
+            &self.cache_order
+        } else {
+            &self.idle_order
+        }
+    }
+
+    /// Retourne l'instant présent
+    fn now(&self) -> Instant {
+        self.clock.now()
+    }
+
+    /// Indique si l'élément d'un emplacement occupé a expiré, par sa durée de vie ou faute d'utilisation
+    ///
+    /// Une échéance trop lointaine pour être représentable revient à ne pas expirer
+    fn is_expired(&self, slot: usize) -> bool {
+        let deadline = match (self.node(slot).expires_at, self.idle_deadline(slot)) {
+            (Some(expires_at), Some(idle_deadline)) => Some(expires_at.min(idle_deadline)),
+            (expires_at, idle_deadline) => expires_at.or(idle_deadline),
+        };
+        deadline.is_some_and(|deadline| deadline <= self.now())
+    }
+
+    /// Retourne l'instant où l'élément d'un emplacement occupé expire faute d'utilisation
+    ///
+    /// `None` si les éléments n'expirent pas faute d'utilisation ou si l'instant n'est pas représentable
+    fn idle_deadline(&self, slot: usize) -> Option<Instant> {
+        let time_to_idle = self.time_to_idle?;
+        let last_access = Duration::from_nanos(self.node(slot).last_access.load(Ordering::Relaxed));
+        self.idle_epoch.checked_add(last_access)?.checked_add(time_to_idle)
+    }
+
+    /// Repousse l'expiration d'un élément inutilisé, si le cache en a une
+    ///
+    /// La dernière utilisation est stockée en nanosecondes depuis `idle_epoch` dans un atomique :
+    /// `peek` peut la modifier sans emprunter le cache de façon modifiable
+    fn touch(&self, slot: usize) {
+        if self.time_to_idle.is_some() {
+            let elapsed = self.now().saturating_duration_since(self.idle_epoch);
+            let last_access = u64::try_from(elapsed.as_nanos()).unwrap_or(u64::MAX);
+            self.node(slot).last_access.store(last_access, Ordering::Relaxed);
+        }
+    }
+
+    /// Change l'instant d'expiration d'un emplacement occupé, `ttl` étant compté à partir de maintenant
+    ///
+    /// Une durée trop grande pour que l'instant d'expiration soit représentable revient à ne pas expirer
+    fn set_expiry(&mut self, slot: usize, ttl: Option<Duration>) {
+        let expires_at = ttl.and_then(|ttl| self.now().checked_add(ttl));
+        let node = self.cache_nodes[slot].as_mut().expect("emplacement vide");
+        if let Some(previous) = std::mem::replace(&mut node.expires_at, expires_at) {
+            self.expirations.remove(&(previous, slot));
+        }
+        if let Some(expires_at) = expires_at {
+            self.expirations.insert((expires_at, slot));
+        }
+    }
+
+    /// Retourne l'emplacement de la clé si elle est présente et n'a pas expiré
+    ///
+    /// Une clé expirée est retirée du cache
+    fn find_live<Q>(&mut self, hash: u64, key: &Q) -> Option<usize>
+    where
+        K: Borrow<Q>,
+        Q: Eq + ?Sized,
+    {
+        let slot = self.find(hash, key)?;
+        if self.is_expired(slot) {
+            self.evict_slot(slot, RemovalCause::Expired);
+            return None;
+        }
+        Some(slot)
+    }
+
+    /// Retourne l'emplacement de la clé si elle est présente et n'a pas expiré
+    ///
+    /// Une clé expirée est retirée du cache
+    fn lookup_live<Q>(&mut self, key: &Q) -> Option<usize>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.find_live(self.hash_builder.hash_one(key), key)
+    }
+
+    /// Ajoute ou remplace un élément, avec la durée de vie `ttl` ou celle par défaut si `ttl` vaut `None`
+    fn put_expiring(&mut self, key: K, value: V, ttl: Option<Duration>) -> PutResult<K, V> {
+        let hash = self.hash_builder.hash_one(&key);
+        let slot = self.find_live(hash, &key);
+        if self.rejects(&key, &value) {
+            // L'élément ne peut pas être conservé, même seul dans le cache : le cache n'est pas modifié
+            return PutResult::Rejected(key, value);
+        }
+        let (slot, result) = if let Some(slot) = slot {
+            // Met à jour la valeur
+            self.promote(slot);
+            let (old_value, evicted) = self.replace_value(slot, value);
+            (slot, PutResult::Replaced(old_value, evicted))
+        } else {
+            match self.insert_new(hash, key, value) {
+                (slot, evicted) if evicted.is_empty() => (slot, PutResult::Inserted),
+                (slot, evicted) => (slot, PutResult::Evicted(evicted)),
+            }
+        };
+        if ttl.is_some() {
+            self.set_expiry(slot, ttl);
+        }
+        result
+    }
+
+    /// Retourne le nombre d'éléments présents dans le cache
+    pub(super) fn cache_len(&self) -> usize {
+        self.cache_order.len()
+    }
+
+    /// Retire et retourne l'élément le plus ancien du cache
+    pub(super) fn pop_front(&mut self) -> Option<(K, V)> {
+        let slot = self.cache_order.front()?;
+        let node = self.remove_slot(slot);
+        Some((node.key, node.value))
+    }
+
+    /// Retire et retourne l'élément le plus récent du cache
+    pub(super) fn pop_back(&mut self) -> Option<(K, V)> {
+        let slot = self.cache_order.back()?;
+        let node = self.remove_slot(slot);
+        Some((node.key, node.value))
+    }
+
+    /// Range un élément dans un emplacement libre et retourne son indice
+    fn allocate(&mut self, node: Node<K, V>) -> usize {
+        match self.free_slots.pop() {
+            Some(slot) => {
+                self.cache_nodes[slot] = Some(node);
+                slot
+            }
+            None => {
+                self.cache_nodes.push(Some(node));
+                self.cache_nodes.len() - 1
+            }
+        }
+    }
+
+    /// Libère un emplacement et retourne l'élément qu'il contenait
+    fn release(&mut self, slot: usize) -> Node<K, V> {
+        let node = self.cache_nodes[slot].take().expect("emplacement vide");
+        self.free_slots.push(slot);
+        node
+    }
+
+    /// Retourne l'emplacement de la clé si elle est présente dans le cache
+    fn find<Q>(&self, hash: u64, key: &Q) -> Option<usize>
+    where
+        K: Borrow<Q>,
+        Q: Eq + ?Sized,
+    {
+        let mut current = self.cache_content.get(&hash).copied();
+        while let Some(slot) = current {
+            let node = self.node(slot);
+            if node.key.borrow() == key {
+                return Some(slot);
+            }
+            current = node.next_same_hash;
+        }
+        None
+    }
+
+    /// Retourne l'emplacement de la clé si elle est présente dans le cache
+    fn lookup<Q>(&self, key: &Q) -> Option<usize>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.find(self.hash_builder.hash_one(key), key)
+    }
+
+    /// Calcule le poids d'un couple clé-valeur, 1 sans fonction de poids
+    fn weigh(&self, key: &K, value: &V) -> u64 {
+        self.weigher.as_ref().map_or(1, |weigher| weigher(key, value))
+    }
+
+    /// Indique si le cache ne peut pas conserver un couple clé-valeur, même seul
+    pub(super) fn rejects(&self, key: &K, value: &V) -> bool {
+        self.size == 0 || self.too_heavy(self.weigh(key, value))
+    }
+
+    /// Indique si un élément de poids `weight` dépasse à lui seul le poids maximal du cache
+    fn too_heavy(&self, weight: u64) -> bool {
+        self.max_weight.is_some_and(|max_weight| weight > max_weight)
+    }
+
+    /// Retire les éléments choisis par la politique d'éviction tant que le poids total dépasse
+    /// le poids maximal, sans jamais retirer l'emplacement `kept`
+    ///
+    /// Retourne les couples clé-valeur retirés, dans l'ordre de leur retrait
+    fn trim_weight(&mut self, kept: usize) -> Vec<(K, V)> {
+        let mut evicted = Vec::new();
+        while self.too_heavy(self.total_weight) {
+            match self.policy.choose_victim(&self.cache_order) {
+                Some(slot) if slot != kept => evicted.push(self.evict_slot(slot, RemovalCause::Capacity)),
+                _ => break,
+            }
+        }
+        evicted
+    }
+
+    /// Retire un emplacement de l'ordre et du contenu du cache
+    pub(super) fn remove_slot(&mut self, slot: usize) -> Node<K, V> {
+        self.cache_order.remove(slot);
+        self.idle_order.remove(slot);
+        self.policy.on_remove(slot);
+        let node = self.release(slot);
+        self.total_weight -= node.weight;
+        if let Some(expires_at) = node.expires_at {
+            self.expirations.remove(&(expires_at, slot));
+        }
+        // Retire l'emplacement de la chaîne des clés de même hash
+        match self.cache_content.get(&node.hash).copied() {
+            Some(first) if first == slot => match node.next_same_hash {
+                Some(next) => {
+                    self.cache_content.insert(node.hash, next);
+                }
+                None => {
+                    self.cache_content.remove(&node.hash);
+                }
+            },
+            mut current => {
+                while let Some(previous) = current {
+                    let previous = self.node_mut(previous);
+                    if previous.next_same_hash == Some(slot) {
+                        previous.next_same_hash = node.next_same_hash;
+                        break;
+                    }
+                    current = previous.next_same_hash;
+                }
+            }
+        }
+        node
+    }
+
+    /// Retire un emplacement du cache et prévient le listener
+    pub(super) fn evict_slot(&mut self, slot: usize, cause: RemovalCause) -> (K, V) {
+        let node = self.remove_slot(slot);
+        notify(&mut self.removal_listener, &node.key, &node.value, cause);
+        (node.key, node.value)
+    }
+
+    /// Remplace la valeur d'un emplacement, prévient le listener et retourne l'ancienne valeur
+    /// avec les couples clé-valeur retirés
+    ///
+    /// Le poids de l'élément est recalculé, les autres éléments sont retirés si le poids maximal
+    /// est dépassé. L'élément reçoit la durée de vie par défaut
+    pub(super) fn replace_value(&mut self, slot: usize, value: V) -> (V, Vec<(K, V)>) {
+        let node = self.cache_nodes[slot].as_mut().expect("emplacement vide");
+        let old_value = std::mem::replace(&mut node.value, value);
+        notify(&mut self.removal_listener, &node.key, &old_value, RemovalCause::Replaced);
+        let weight = self.weigher.as_ref().map_or(1, |weigher| weigher(&node.key, &node.value));
+        self.total_weight = self.total_weight - std::mem::replace(&mut node.weight, weight) + weight;
+        self.set_expiry(slot, self.default_ttl);
+        (old_value, self.trim_weight(slot))
+    }
+
+    /// Ajoute une nouvelle clé à la fin du cache, en retirant les plus anciennes si le cache est plein
+    ///
+    /// Retourne l'emplacement de la clé et les couples clé-valeur retirés par la politique d'éviction,
+    /// dans l'ordre de leur retrait. Les éléments expirés sont retirés en premier et ne sont
+    /// signalés qu'au listener. L'élément reçoit la durée de vie par défaut
+    ///
+    /// La clé ne doit pas déjà être présente dans le cache, `hash` doit être son hash
+    /// et le cache doit pouvoir conserver l'élément (voir [`Cache::rejects`])
+    pub(super) fn insert_new(&mut self, hash: u64, key: K, value: V) -> (usize, Vec<(K, V)>) {
+        debug_assert!(!self.rejects(&key, &value));
+        let weight = self.weigh(&key, &value);
+        let mut evicted = Vec::new();
+        let is_full = |cache: &Self| {
+            cache.cache_order.len() >= cache.size || cache.too_heavy(cache.total_weight.saturating_add(weight))
+        };
+        if is_full(self) {
+            self.remove_expired();
+        }
+        self.policy.before_insert(hash);
+        while is_full(self) {
+            // Enlève la clé choisie par la politique d'éviction
+            let Some(slot_supprime) = self.policy.choose_victim(&self.cache_order) else {
+                break;
+            };
+            evicted.push(self.evict_slot(slot_supprime, RemovalCause::Capacity));
+        }
+        // Ajoute la nouvelle pair de clé-valeur
+        let next_same_hash = self.cache_content.get(&hash).copied();
+        let slot = self.allocate(Node {
+            key,
+            value,
+            hash,
+            weight,
+            expires_at: None,
+            last_access: AtomicU64::new(0),
+            refreshed_by_peek: AtomicBool::new(false),
+            next_same_hash,
+        });
+        self.total_weight += weight;
+        self.set_expiry(slot, self.default_ttl);
+        self.touch(slot);
+        self.cache_order.push_back(slot);
+        if self.time_to_idle.is_some() && !P::PROMOTE_ON_ACCESS {
+            self.idle_order.push_back(slot);
+        }
+        self.cache_content.insert(hash, slot);
+        self.policy.on_insert(slot, hash);
+        (slot, evicted)
+    }
+
+    /// Place un emplacement à la fin du cache (élément le plus récent) et de l'ordre des utilisations
+    ///
+    /// L'ordre du cache n'est pas modifié si la politique ne le demande pas
+    /// (voir [`EvictionPolicy::PROMOTE_ON_ACCESS`])
+    pub(super) fn promote(&mut self, slot: usize) {
+        if P::PROMOTE_ON_ACCESS {
+            self.cache_order.move_to_back(slot);
+        } else if self.time_to_idle.is_some() {
+            self.idle_order.move_to_back(slot);
+        }
+        if self.time_to_idle.is_some() {
+            self.node(slot).refreshed_by_peek.store(false, Ordering::Relaxed);
+        }
+        self.touch(slot);
+        self.policy.on_access(slot);
+    }
+
+    /// Compte la consultation d'un emplacement par `peek` comme une utilisation, si le cache le demande
+    fn touch_on_peek(&self, slot: usize) {
+        if self.refresh_on_peek && self.time_to_idle.is_some() {
+            self.touch(slot);
+            self.node(slot).refreshed_by_peek.store(true, Ordering::Relaxed);
+        }
+    }
+
+    /// Retourne l'élément rangé dans un emplacement occupé
+    pub(super) fn node(&self, slot: usize) -> &Node<K, V> {
+        self.cache_nodes[slot].as_ref().expect("emplacement vide")
+    }
+
+    /// Retourne l'élément rangé dans un emplacement occupé
+    pub(super) fn node_mut(&mut self, slot: usize) -> &mut Node<K, V> {
+        self.cache_nodes[slot].as_mut().expect("emplacement vide")
+    }
+}
+
+impl<K, V, P> TraitCache<K, V> for Cache<K, V, P>
+where
+    K: Eq + Hash,
+    P: EvictionPolicy,
+{
+    /// Ajoute une clé et sa valeur associée dans le cache
+    ///
+    /// # Arguments
+    /// - `key` : La clé à insérer
+    /// - `value` : La valeur associée à la clé
+    ///
+    /// # Return
+    /// - `PutResult<K, V>` : L'ancienne valeur si la clé était présente,
+    ///   les couples clé-valeur retirés si le cache était plein
+    ///
+    /// # Exemples
+    ///
+    /// ```
+    /// use hashmap_cache::cache::Cache;
+    /// use hashmap_cache::cache::trait_cache::{PutResult, TraitCache};
+    ///
+    /// let mut cache: Cache<&str, String> = Cache::new(2);
+    ///
+    /// cache.put("A", String::from("value_a")); // [A]
+    /// cache.put("B", String::from("value_b")); // [A,B]
+    /// cache.put("C", String::from("value_c")); // [B,C] ("A" est supprimé car la taille du cache est de 2)
+    ///
+    /// assert_eq!(cache.put("D", String::from("value_d")), PutResult::Evicted(vec![("B", String::from("value_b"))])); // [C,D]
+    /// assert_eq!(cache.put("C", String::from("value_c2")), PutResult::Replaced(String::from("value_c"), vec![])); // [D,C]
+    /// ```
+    ///
+    /// Un cache de taille 0 ne conserve pas l'élément, tout comme un cache pondéré
+    /// ne conserve pas un élément plus lourd que son poids maximal (voir [`Cache::with_weigher`]) :
+    ///
+    /// ```
+    /// use hashmap_cache::cache::Cache;
+    /// use hashmap_cache::cache::trait_cache::{PutResult, TraitCache};
+    ///
+    /// let mut cache: Cache<&str, String> = Cache::new(0);
+    ///
+    /// assert_eq!(cache.put("A", String::from("value_a")), PutResult::Rejected("A", String::from("value_a"))); // []
+    /// assert!(cache.is_empty());
+    /// ```
+    fn put(&mut self, key: K, value: V) -> PutResult<K, V> {
+        self.put_expiring(key, value, None)
+    }
+
+    /// Retourne la valeur V de la clé K
+    /// et place l'élément à la fin du cache (élément le plus récent)
+    ///
+    /// La clé peut être passée sous n'importe quelle forme empruntée de K
+    /// (par exemple `&str` pour un cache dont les clés sont des `String`)
+    ///
+    /// Une clé expirée est absente et elle est retirée du cache
+    ///
+    /// # Arguments
+    /// - `key` : La clé dont on veut obtenir la valeur
+    ///
+    /// # Return
+    /// - `Option<&V>` : La valeur associée si la clé existe sinon `None`
+    ///
+    /// # Exemples
+    ///
+    /// ```
+    /// use hashmap_cache::cache::Cache;
+    /// use hashmap_cache::cache::trait_cache::TraitCache;
+    ///
+    /// let mut cache = Cache::new(3);
+    ///
+    /// cache.put("A", String::from("value_a")); // [A]
+    /// cache.put("B", String::from("value_b")); // [A,B]
+    /// cache.put("C", String::from("value_c")); // [A,B,C]
+    ///
+    /// assert_eq!(cache.get("A"), Some(&String::from("value_a"))); // [B,C,A]
+    /// assert_eq!(cache.get("X"), None); // "X" n'est pas dans le cache
+    /// ```
+    fn get<Q>(&mut self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let slot = self.lookup_live(key)?;
+        self.promote(slot);
+        Some(&self.node_mut(slot).value)
+    }
+
+    /// Retourne la valeur V de la clé K de façon modifiable
+    /// et place l'élément à la fin du cache (élément le plus récent)
+    ///
+    /// # Arguments
+    /// - `key` : La clé dont on veut modifier la valeur
+    ///
+    /// # Return
+    /// - `Option<&mut V>` : La valeur associée si la clé existe sinon `None`
+    ///
+    /// # Exemples
+    ///
+    /// ```
+    /// use hashmap_cache::cache::Cache;
+    /// use hashmap_cache::cache::trait_cache::TraitCache;
+    ///
+    /// let mut cache = Cache::new(2);
+    ///
+    /// cache.put("A", 1); // [A]
+    /// cache.put("B", 2); // [A,B]
+    ///
+    /// if let Some(value) = cache.get_mut("A") {
+    ///     *value += 10;
+    /// } // [B,A]
+    ///
+    /// cache.put("C", 3); // [A,C] ("B" est supprimé)
+    /// assert_eq!(cache.get("A"), Some(&11));
+    /// ```
+    fn get_mut<Q>(&mut self, key: &Q) -> Option<&mut V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let slot = self.lookup_live(key)?;
+        self.promote(slot);
+        Some(&mut self.node_mut(slot).value)
+    }
+
+    /// Retourne la valeur V de la clé K sans modifier l'ordre du cache
+    ///
+    /// Une clé expirée est absente, mais elle n'est retirée que par la prochaine méthode
+    /// qui modifie le cache
+    ///
+    /// # Arguments
+    /// - `key` : La clé dont on veut obtenir la valeur
+    ///
+    /// # Return
+    /// - `Option<&V>` : La valeur associée si la clé existe sinon `None`
+    ///
+    /// # Exemples
+    ///
+    /// ```
+    /// use hashmap_cache::cache::Cache;
+    /// use hashmap_cache::cache::trait_cache::TraitCache;
+    ///
+    /// let mut cache = Cache::new(2);
+    ///
+    /// cache.put("A", String::from("value_a")); // [A]
+    /// cache.put("B", String::from("value_b")); // [A,B]
+    ///
+    /// assert_eq!(cache.peek(&"A"), Some(&String::from("value_a"))); // [A,B]
+    ///
+    /// cache.put("C", String::from("value_c")); // [B,C] ("A" reste le plus ancien)
+    /// assert_eq!(cache.peek(&"A"), None);
+    /// ```
+    fn peek<Q>(&self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let slot = self.lookup(key).filter(|&slot| !self.is_expired(slot))?;
+        self.touch_on_peek(slot);
+        Some(&self.node(slot).value)
+    }
+
+    /// Retourne la valeur V de la clé K de façon modifiable sans modifier l'ordre du cache
+    ///
+    /// # Arguments
+    /// - `key` : La clé dont on veut modifier la valeur
+    ///
+    /// # Return
+    /// - `Option<&mut V>` : La valeur associée si la clé existe sinon `None`
+    ///
+    /// # Exemples
+    ///
+    /// ```
+    /// use hashmap_cache::cache::Cache;
+    /// use hashmap_cache::cache::trait_cache::TraitCache;
+    ///
+    /// let mut cache = Cache::new(2);
+    ///
+    /// cache.put("A", 1); // [A]
+    /// cache.put("B", 2); // [A,B]
+    ///
+    /// if let Some(value) = cache.peek_mut("A") {
+    ///     *value += 10;
+    /// } // [A,B]
+    ///
+    /// cache.put("C", 3); // [B,C] ("A" reste le plus ancien)
+    /// assert_eq!(cache.get("A"), None);
+    /// ```
+    fn peek_mut<Q>(&mut self, key: &Q) -> Option<&mut V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let slot = self.lookup_live(key)?;
+        self.touch_on_peek(slot);
+        Some(&mut self.node_mut(slot).value)
+    }
+
+    /// Indique si la clé K est présente dans le cache sans modifier l'ordre du cache
+    ///
+    /// # Arguments
+    /// - `key` : La clé recherchée
+    ///
+    /// # Exemples
+    ///
+    /// ```
+    /// use hashmap_cache::cache::Cache;
+    /// use hashmap_cache::cache::trait_cache::TraitCache;
+    ///
+    /// let mut cache = Cache::new(2);
+    ///
+    /// cache.put("A", String::from("value_a")); // [A]
+    ///
+    /// assert!(cache.contains(&"A"));
+    /// assert!(!cache.contains(&"X"));
+    /// ```
+    fn contains<Q>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.lookup(key).is_some_and(|slot| !self.is_expired(slot))
+    }
+
+    /// Retire la clé K du cache et retourne sa valeur
+    ///
+    /// # Arguments
+    /// - `key` : La clé à retirer
+    ///
+    /// # Return
+    /// - `Option<V>` : La valeur retirée si la clé existait sinon `None`
+    ///
+    /// # Exemples
+    ///
+    /// ```
+    /// use hashmap_cache::cache::Cache;
+    /// use hashmap_cache::cache::trait_cache::TraitCache;
+    ///
+    /// let mut cache = Cache::new(2);
+    ///
+    /// cache.put("A", String::from("value_a")); // [A]
+    /// cache.put("B", String::from("value_b")); // [A,B]
+    ///
+    /// assert_eq!(cache.remove(&"A"), Some(String::from("value_a"))); // [B]
+    /// assert_eq!(cache.remove(&"A"), None);
+    /// assert_eq!(cache.len(), 1);
+    /// ```
+    fn remove<Q>(&mut self, key: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let slot = self.lookup_live(key)?;
+        Some(self.evict_slot(slot, RemovalCause::Explicit).1)
+    }
+
+    /// Retourne le nombre d'éléments présents dans le cache
+    ///
+    /// # Exemples
+    ///
+    /// ```
+    /// use hashmap_cache::cache::Cache;
+    /// use hashmap_cache::cache::trait_cache::TraitCache;
+    ///
+    /// let mut cache = Cache::new(2);
+    ///
+    /// cache.put("A", String::from("value_a")); // [A]
+    /// assert_eq!(cache.len(), 1);
+    /// ```
+    fn len(&self) -> usize {
+        self.cache_len()
+    }
+
+    /// Indique si le cache est vide
+    ///
+    /// # Exemples
+    ///
+    /// ```
+    /// use hashmap_cache::cache::Cache;
+    /// use hashmap_cache::cache::trait_cache::TraitCache;
+    ///
+    /// let cache: Cache<&str, String> = Cache::new(2);
+    /// assert!(cache.is_empty());
+    /// ```
+    fn is_empty(&self) -> bool {
+        self.cache_len() == 0
+    }
+
+    /// Retourne la taille maximale du cache
+    ///
+    /// # Exemples
+    ///
+    /// ```
+    /// use hashmap_cache::cache::Cache;
+    /// use hashmap_cache::cache::trait_cache::TraitCache;
+    ///
+    /// let cache: Cache<&str, String> = Cache::new(2);
+    /// assert_eq!(cache.capacity(), 2);
+    /// ```
+    fn capacity(&self) -> usize {
+        self.size
+    }
+
+    /// Déplace une clé à la fin du cache
+    ///
+    /// # Arguments
+    /// - `key` : La clé que l'on veut déplacer
+    ///
+    /// # Exemples
+    ///
+    /// ```
+    /// use hashmap_cache::cache::Cache;
+    /// use hashmap_cache::cache::trait_cache::TraitCache;
+    ///
+    /// let mut cache = Cache::new(3);
+    ///
+    /// cache.put("A", String::from("value_a")); // [A]
+    /// cache.put("B", String::from("value_b")); // [A,B]
+    /// cache.put("C", String::from("value_c")); // [A,B,C]
+    ///
+    /// cache.move_key_end_cache(&"A"); // [B,C,A]
+    /// ```
+    fn move_key_end_cache<Q>(&mut self, key: &Q)
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        if let Some(slot) = self.lookup_live(key) {
+            self.promote(slot);
+        }
+    }
+}
+
+impl<K, V, P> IntoIterator for Cache<K, V, P>
+where
+    K: Eq + Hash,
+    P: EvictionPolicy,
+{
+    type Item = (K, V);
+    type IntoIter = IntoIter<K, V, P>;
+
+    /// Consomme le cache et retourne ses couples clé-valeur, du plus récent au plus ancien
+    fn into_iter(self) -> IntoIter<K, V, P> {
+        IntoIter { cache: self }
+    }
+}
+
+impl<'a, K, V, P> IntoIterator for &'a Cache<K, V, P>
+where
+    K: Eq + Hash,
+    P: EvictionPolicy,
+{
+    type Item = (&'a K, &'a V);
+    type IntoIter = Iter<'a, K, V>;
+
+    fn into_iter(self) -> Iter<'a, K, V> {
+        self.iter()
+    }
+}
+
+impl<'a, K, V, P> IntoIterator for &'a mut Cache<K, V, P>
+where
+    K: Eq + Hash,
+    P: EvictionPolicy,
+{
+    type Item = (&'a K, &'a mut V);
+    type IntoIter = IterMut<'a, K, V>;
+
+    fn into_iter(self) -> IterMut<'a, K, V> {
+        self.iter_mut()
+    }
+}
+
+impl<K, V, P> Extend<(K, V)> for Cache<K, V, P>
+where
+    K: Eq + Hash,
+    P: EvictionPolicy,
+{
+    /// Ajoute les couples clé-valeur dans l'ordre, comme des appels successifs à `put`
+    ///
+    /// Les éléments les plus anciens sont retirés au fur et à mesure si le cache est plein.
+    /// `Cache` n'implémente pas `FromIterator` car sa taille ne peut pas être déduite des éléments :
+    /// pour remplir un nouveau cache, le créer avec sa taille (`new`, `with_policy`...) puis appeler `extend`
+    ///
+    /// # Exemples
+    ///
+    /// ```
+    /// use hashmap_cache::cache::Cache;
+    /// use hashmap_cache::cache::trait_cache::TraitCache;
+    ///
+    /// let mut cache = Cache::new(2);
+    ///
+    /// cache.extend([("A", 1), ("B", 2), ("C", 3)]); // [B,C]
+    ///
+    /// assert_eq!(cache.keys().collect::<Vec<_>>(), [&"C", &"B"]);
+    /// ```
+    fn extend<I: IntoIterator<Item = (K, V)>>(&mut self, iter: I) {
+        for (key, value) in iter {
+            self.put(key, value);
+        }
+    }
+}
@@ -0,0 +1,81 @@
+use std::sync::{Arc, Mutex, PoisonError};
+use std::time::{Duration, Instant};
+
+/// Source du temps utilisée par le cache pour les durées de vie et l'expiration faute d'utilisation
+///
+/// Le cache utilise [`SystemClock`] par défaut, [`MockClock`] permet de contrôler le temps
+/// dans les tests, voir [`Cache::set_clock`](crate::cache::Cache::set_clock)
+pub trait Clock: Send + Sync {
+    /// Retourne l'instant présent
+    fn now(&self) -> Instant;
+}
+
+/// Horloge du système, basée sur [`Instant::now`]
+#[derive(Debug, Default, Clone, Copy)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> Instant {
+        Instant::now()
+    }
+}
+
+/// Horloge arrêtée qui n'avance que lorsqu'on le lui demande
+///
+/// Les clones d'une horloge partagent le même temps, même depuis d'autres threads :
+/// on donne un clone au cache et on fait avancer l'original
+///
+/// # Exemples
+///
+/// ```
+/// use std::time::Duration;
+/// use hashmap_cache::cache::{Clock, MockClock};
+///
+/// let clock = MockClock::new();
+/// let shared = clock.clone();
+/// let start = shared.now();
+///
+/// std::thread::spawn(move || clock.advance(Duration::from_secs(5))).join().unwrap();
+///
+/// assert_eq!(shared.now() - start, Duration::from_secs(5));
+/// ```
+#[derive(Debug, Clone)]
+pub struct MockClock {
+    start: Instant,
+    elapsed: Arc<Mutex<Duration>>,
+}
+
+impl MockClock {
+    /// Créé une horloge arrêtée à l'instant présent
+    pub fn new() -> Self {
+        Self {
+            start: Instant::now(),
+            elapsed: Arc::new(Mutex::new(Duration::ZERO)),
+        }
+    }
+
+    /// Fait avancer l'horloge, et tous ses clones, de `duration`
+    ///
+    /// # Arguments
+    /// - `duration` : La durée dont l'horloge avance
+    pub fn advance(&self, duration: Duration) {
+        *self.elapsed.lock().unwrap_or_else(PoisonError::into_inner) += duration;
+    }
+
+    /// Retourne la durée dont l'horloge a avancé depuis sa création
+    fn elapsed(&self) -> Duration {
+        *self.elapsed.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl Default for MockClock {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Clock for MockClock {
+    fn now(&self) -> Instant {
+        self.start + self.elapsed()
+    }
+}
@@ -0,0 +1,239 @@
+use std::hash::Hash;
+use crate::cache::{Cache, RemovalCause};
+use crate::cache::policy::{EvictionPolicy, Lru};
+
+/// Vue sur une clé du cache, présente ou absente, obtenue avec [`Cache::entry`]
+///
+/// Permets de lire, modifier ou insérer une valeur avec une seule recherche de la clé
+///
+pub enum Entry<'a, K, V, P = Lru> {
+    /// La clé est présente dans le cache
+    Occupied(OccupiedEntry<'a, K, V, P>),
+    /// La clé est absente du cache
+    Vacant(VacantEntry<'a, K, V, P>),
+}
+
+/// Clé présente dans le cache
+///
+/// L'élément a déjà été placé à la fin du cache (élément le plus récent) lors de l'appel à [`Cache::entry`]
+pub struct OccupiedEntry<'a, K, V, P = Lru> {
+    pub(super) cache: &'a mut Cache<K, V, P>,
+    pub(super) slot: usize,
+}
+
+/// Clé absente du cache
+pub struct VacantEntry<'a, K, V, P = Lru> {
+    pub(super) cache: &'a mut Cache<K, V, P>,
+    pub(super) key: K,
+    pub(super) hash: u64,
+}
+
+impl<'a, K, V, P> Entry<'a, K, V, P>
+where
+    K: Eq + Hash,
+    P: EvictionPolicy,
+{
+    /// Retourne la clé de l'entrée
+    pub fn key(&self) -> &K {
+        match self {
+            Entry::Occupied(entry) => entry.key(),
+            Entry::Vacant(entry) => entry.key(),
+        }
+    }
+
+    /// Insère `value` si la clé est absente et retourne la valeur du cache
+    ///
+    /// # Arguments
+    /// - `value` : La valeur à insérer si la clé est absente
+    ///
+    /// # Return
+    /// - `Result<&'a mut V, V>` : La valeur du cache, ou `value` si le cache ne peut pas la conserver
+    ///   (voir [`VacantEntry::insert`])
+    ///
+    /// # Exemples
+    ///
+    /// ```
+    /// use hashmap_cache::cache::Cache;
+    ///
+    /// let mut cache = Cache::new(2);
+    ///
+    /// *cache.entry("A").or_insert(0).unwrap() += 1; // [A]
+    /// *cache.entry("A").or_insert(0).unwrap() += 1; // [A]
+    ///
+    /// assert_eq!(cache.entry("A").or_insert(0), Ok(&mut 2));
+    /// ```
+    pub fn or_insert(self, value: V) -> Result<&'a mut V, V> {
+        self.or_insert_with(|| value)
+    }
+
+    /// Insère la valeur calculée par `default` si la clé est absente et retourne la valeur du cache
+    ///
+    /// `default` n'est appelée que si la clé est absente
+    ///
+    /// # Arguments
+    /// - `default` : La fonction qui calcule la valeur à insérer
+    ///
+    /// # Return
+    /// - `Result<&'a mut V, V>` : La valeur du cache, ou la valeur calculée si le cache ne peut pas
+    ///   la conserver (voir [`VacantEntry::insert`])
+    ///
+    /// # Exemples
+    ///
+    /// ```
+    /// use hashmap_cache::cache::Cache;
+    ///
+    /// let mut cache = Cache::new(2);
+    ///
+    /// cache.entry("A").or_insert_with(|| String::from("value_a")).unwrap(); // [A]
+    ///
+    /// assert_eq!(cache.entry("A").or_insert_with(|| unreachable!()).unwrap(), "value_a");
+    /// ```
+    pub fn or_insert_with<F>(self, default: F) -> Result<&'a mut V, V>
+    where
+        F: FnOnce() -> V,
+    {
+        match self {
+            Entry::Occupied(entry) => Ok(entry.into_mut()),
+            Entry::Vacant(entry) => entry.insert(default()),
+        }
+    }
+
+    /// Modifie la valeur en place si la clé est présente
+    ///
+    /// # Arguments
+    /// - `f` : La fonction appliquée à la valeur présente
+    ///
+    /// # Exemples
+    ///
+    /// ```
+    /// use hashmap_cache::cache::Cache;
+    ///
+    /// let mut cache = Cache::new(2);
+    ///
+    /// cache.entry("A").and_modify(|v| *v += 1).or_insert(0).unwrap(); // [A] ("A" vaut 0)
+    /// cache.entry("A").and_modify(|v| *v += 1).or_insert(0).unwrap(); // [A] ("A" vaut 1)
+    ///
+    /// assert_eq!(cache.entry("A").or_insert(0), Ok(&mut 1));
+    /// ```
+    pub fn and_modify<F>(self, f: F) -> Self
+    where
+        F: FnOnce(&mut V),
+    {
+        match self {
+            Entry::Occupied(mut entry) => {
+                f(entry.get_mut());
+                Entry::Occupied(entry)
+            }
+            Entry::Vacant(entry) => Entry::Vacant(entry),
+        }
+    }
+}
+
+impl<'a, K, V, P> OccupiedEntry<'a, K, V, P>
+where
+    K: Eq + Hash,
+    P: EvictionPolicy,
+{
+    /// Retourne la clé de l'entrée
+    pub fn key(&self) -> &K {
+        &self.cache.node(self.slot).key
+    }
+
+    /// Retourne la valeur de l'entrée
+    pub fn get(&self) -> &V {
+        &self.cache.node(self.slot).value
+    }
+
+    /// Retourne la valeur de l'entrée de façon modifiable
+    pub fn get_mut(&mut self) -> &mut V {
+        &mut self.cache.node_mut(self.slot).value
+    }
+
+    /// Convertit l'entrée en une référence modifiable vers la valeur, liée à la durée de vie du cache
+    pub fn into_mut(self) -> &'a mut V {
+        &mut self.cache.node_mut(self.slot).value
+    }
+
+    /// Remplace la valeur de l'entrée et retourne l'ancienne
+    ///
+    /// Les éléments retirés pour respecter le poids maximal d'un cache pondéré ne sont signalés
+    /// qu'au listener, `put` les retourne. Une valeur plus lourde que le poids maximal est refusée
+    /// et l'entrée garde sa valeur, voir [`Cache::with_weigher`]
+    ///
+    /// # Return
+    /// - `Result<V, V>` : L'ancienne valeur, ou `value` si le cache ne peut pas la conserver
+    ///
+    /// # Exemples
+    ///
+    /// ```
+    /// use hashmap_cache::cache::{Cache, Entry};
+    ///
+    /// let mut cache = Cache::with_weigher(10, |_key: &&str, value: &Vec<u8>| value.len() as u64);
+    /// cache.entry("A").or_insert(vec![0; 4]).unwrap(); // [A], poids 4
+    ///
+    /// if let Entry::Occupied(mut entry) = cache.entry("A") {
+    ///     assert_eq!(entry.insert(vec![1; 6]), Ok(vec![0; 4]));
+    ///     assert_eq!(entry.insert(vec![2; 50]), Err(vec![2; 50]));
+    ///     assert_eq!(entry.get(), &vec![1; 6]);
+    /// }
+    /// assert_eq!(cache.weight(), 6);
+    /// ```
+    pub fn insert(&mut self, value: V) -> Result<V, V> {
+        if self.cache.rejects(self.key(), &value) {
+            return Err(value);
+        }
+        Ok(self.cache.replace_value(self.slot, value).0)
+    }
+
+    /// Retire l'entrée du cache et retourne sa valeur
+    pub fn remove(self) -> V {
+        self.cache.evict_slot(self.slot, RemovalCause::Explicit).1
+    }
+}
+
+impl<'a, K, V, P> VacantEntry<'a, K, V, P>
+where
+    K: Eq + Hash,
+    P: EvictionPolicy,
+{
+    /// Retourne la clé de l'entrée
+    pub fn key(&self) -> &K {
+        &self.key
+    }
+
+    /// Retourne la clé sans modifier le cache
+    pub fn into_key(self) -> K {
+        self.key
+    }
+
+    /// Insère la valeur à la fin du cache et retourne une référence modifiable vers elle
+    ///
+    /// Si le cache est plein, l'élément le plus ancien est retiré et n'est signalé qu'au listener,
+    /// `put` le retourne. Un cache de taille 0, ou un cache
+    /// pondéré dont le poids maximal est dépassé par le seul nouvel élément, ne conserve pas
+    /// la valeur : elle est rendue à l'appelant et le cache n'est pas modifié
+    ///
+    /// # Return
+    /// - `Result<&'a mut V, V>` : La valeur insérée, ou `value` si le cache ne peut pas la conserver
+    ///
+    /// # Exemples
+    ///
+    /// ```
+    /// use hashmap_cache::cache::{Cache, Entry};
+    /// use hashmap_cache::cache::trait_cache::TraitCache;
+    ///
+    /// let mut cache = Cache::new(0);
+    ///
+    /// if let Entry::Vacant(entry) = cache.entry("A") {
+    ///     assert_eq!(entry.insert(1), Err(1));
+    /// }
+    /// assert!(cache.is_empty());
+    /// ```
+    pub fn insert(self, value: V) -> Result<&'a mut V, V> {
+        if self.cache.rejects(&self.key, &value) {
+            return Err(value);
+        }
+        let (slot, _) = self.cache.insert_new(self.hash, self.key, value);
+        Ok(&mut self.cache.node_mut(slot).value)
+    }
+}
@@ -0,0 +1,135 @@
+use std::collections::{BTreeMap, HashMap, HashSet, VecDeque};
+use std::mem::size_of;
+
+/// Estimation de la mémoire allouée sur le tas par une valeur
+///
+/// Seuls les octets alloués par la valeur sont comptés, pas ceux de la valeur elle-même
+/// (`size_of::<T>()`) : un `u64` ne possède rien sur le tas, une `String` possède sa capacité.
+/// Les estimations ne tiennent pas compte de la gestion interne de l'allocateur
+///
+/// Utilisée par [`Cache::with_memory_limit`](crate::cache::Cache::with_memory_limit) pour borner
+/// un cache par la mémoire qu'il occupe
+///
+/// # Exemples
+///
+/// ```
+/// use hashmap_cache::cache::HeapSize;
+///
+/// let value = (String::with_capacity(10), vec![1u32, 2, 3]);
+///
+/// assert_eq!(value.heap_size(), 10 + 3 * 4);
+/// assert_eq!(42u64.heap_size(), 0);
+/// ```
+pub trait HeapSize {
+    /// Retourne le nombre d'octets alloués sur le tas par la valeur
+    fn heap_size(&self) -> usize;
+}
+
+/// Implémente `HeapSize` pour des types qui n'allouent rien sur le tas
+macro_rules! impl_no_heap {
+    ($($type:ty),*) => {
+        $(
+            impl HeapSize for $type {
+                fn heap_size(&self) -> usize {
+                    0
+                }
+            }
+        )*
+    };
+}
+
+impl_no_heap!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64, bool, char, ());
+
+/// Une référence ne possède pas la valeur empruntée
+impl<T: ?Sized> HeapSize for &T {
+    fn heap_size(&self) -> usize {
+        0
+    }
+}
+
+impl HeapSize for String {
+    fn heap_size(&self) -> usize {
+        self.capacity()
+    }
+}
+
+impl HeapSize for Box<str> {
+    fn heap_size(&self) -> usize {
+        self.len()
+    }
+}
+
+impl<T: HeapSize> HeapSize for Box<T> {
+    fn heap_size(&self) -> usize {
+        size_of::<T>() + self.as_ref().heap_size()
+    }
+}
+
+impl<T: HeapSize> HeapSize for Box<[T]> {
+    fn heap_size(&self) -> usize {
+        self.len() * size_of::<T>() + self.iter().map(HeapSize::heap_size).sum::<usize>()
+    }
+}
+
+impl<T: HeapSize> HeapSize for Option<T> {
+    fn heap_size(&self) -> usize {
+        self.as_ref().map_or(0, HeapSize::heap_size)
+    }
+}
+
+impl<T: HeapSize, const N: usize> HeapSize for [T; N] {
+    fn heap_size(&self) -> usize {
+        self.iter().map(HeapSize::heap_size).sum()
+    }
+}
+
+impl<T: HeapSize> HeapSize for Vec<T> {
+    fn heap_size(&self) -> usize {
+        self.capacity() * size_of::<T>() + self.iter().map(HeapSize::heap_size).sum::<usize>()
+    }
+}
+
+impl<T: HeapSize> HeapSize for VecDeque<T> {
+    fn heap_size(&self) -> usize {
+        self.capacity() * size_of::<T>() + self.iter().map(HeapSize::heap_size).sum::<usize>()
+    }
+}
+
+/// Chaque case de la table compte un couple clé-valeur et un octet de contrôle
+impl<K: HeapSize, V: HeapSize, S> HeapSize for HashMap<K, V, S> {
+    fn heap_size(&self) -> usize {
+        self.capacity() * (size_of::<(K, V)>() + 1)
+            + self.iter().map(|(key, value)| key.heap_size() + value.heap_size()).sum::<usize>()
+    }
+}
+
+/// Chaque case de la table compte une valeur et un octet de contrôle
+impl<T: HeapSize, S> HeapSize for HashSet<T, S> {
+    fn heap_size(&self) -> usize {
+        self.capacity() * (size_of::<T>() + 1) + self.iter().map(HeapSize::heap_size).sum::<usize>()
+    }
+}
+
+/// Les nœuds de l'arbre ne sont pas comptés, seulement les couples clé-valeur qu'ils contiennent
+impl<K: HeapSize, V: HeapSize> HeapSize for BTreeMap<K, V> {
+    fn heap_size(&self) -> usize {
+        self.iter().map(|(key, value)| size_of::<(K, V)>() + key.heap_size() + value.heap_size()).sum()
+    }
+}
+
+/// Implémente `HeapSize` pour les tuples dont tous les éléments l'implémentent
+macro_rules! impl_tuple {
+    ($(($($name:ident),+)),*) => {
+        $(
+            impl<$($name: HeapSize),+> HeapSize for ($($name,)+) {
+                #[allow(non_snake_case)]
+                fn heap_size(&self) -> usize {
+                    let ($($name,)+) = self;
+                    0 $(+ $name.heap_size())+
+                }
+            }
+        )*
+    };
+}
+
+impl_tuple!((A), (A, B), (A, B, C), (A, B, C, D), (A, B, C, D, E), (A, B, C, D, E, F));
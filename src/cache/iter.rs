@@ -0,0 +1,257 @@
+use std::hash::Hash;
+use std::iter::FusedIterator;
+use std::marker::PhantomData;
+use crate::cache::cache::Node;
+use crate::cache::list;
+use crate::cache::Cache;
+use crate::cache::policy::{EvictionPolicy, Lru};
+
+/// Itérateur sur les couples clé-valeur du cache, du plus récent au plus ancien
+///
+/// Obtenu avec [`Cache::iter`], `.rev()` parcourt le cache du plus ancien au plus récent
+pub struct Iter<'a, K, V> {
+    pub(super) nodes: &'a [Option<Node<K, V>>],
+    pub(super) order: list::Iter<'a>,
+}
+
+impl<'a, K, V> Iter<'a, K, V> {
+    fn pair(&self, slot: usize) -> (&'a K, &'a V) {
+        let node = self.nodes[slot].as_ref().expect("emplacement vide");
+        (&node.key, &node.value)
+    }
+}
+
+impl<'a, K, V> Iterator for Iter<'a, K, V> {
+    type Item = (&'a K, &'a V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let slot = self.order.next_back()?;
+        Some(self.pair(slot))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.order.size_hint()
+    }
+}
+
+impl<K, V> DoubleEndedIterator for Iter<'_, K, V> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let slot = self.order.next()?;
+        Some(self.pair(slot))
+    }
+}
+
+impl<K, V> ExactSizeIterator for Iter<'_, K, V> {}
+
+impl<K, V> FusedIterator for Iter<'_, K, V> {}
+
+impl<K, V> Clone for Iter<'_, K, V> {
+    fn clone(&self) -> Self {
+        Self {
+            nodes: self.nodes,
+            order: self.order.clone(),
+        }
+    }
+}
+
+/// Itérateur sur les couples clé-valeur modifiables du cache, du plus récent au plus ancien
+///
+/// Obtenu avec [`Cache::iter_mut`]
+pub struct IterMut<'a, K, V> {
+    pub(super) nodes: *mut Option<Node<K, V>>,
+    pub(super) order: list::Iter<'a>,
+    pub(super) marker: PhantomData<&'a mut [Option<Node<K, V>>]>,
+}
+
+// `IterMut` se comporte comme un `&'a mut [Option<Node<K, V>>]`
+unsafe impl<K: Send, V: Send> Send for IterMut<'_, K, V> {}
+unsafe impl<K: Sync, V: Sync> Sync for IterMut<'_, K, V> {}
+
+impl<'a, K, V> IterMut<'a, K, V> {
+    fn pair(&mut self, slot: usize) -> (&'a K, &'a mut V) {
+        // SAFETY : `nodes` vient d'un `&'a mut` sur les emplacements du cache, emprunté pendant 'a,
+        // et `slot` en est un indice valide puisqu'il est dans l'ordre du cache. L'ordre ne passe
+        // qu'une fois par chaque emplacement : deux références modifiables vers le même élément
+        // ne peuvent pas coexister, ce que `test_lru_cache_iter_mut_both_ends` vérifie sous Miri
+        // (`cargo +nightly miri test --lib iter`)
+        let node = unsafe { &mut *self.nodes.add(slot) }.as_mut().expect("emplacement vide");
+        (&node.key, &mut node.value)
+    }
+}
+
+impl<'a, K, V> Iterator for IterMut<'a, K, V> {
+    type Item = (&'a K, &'a mut V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let slot = self.order.next_back()?;
+        Some(self.pair(slot))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.order.size_hint()
+    }
+}
+
+impl<K, V> DoubleEndedIterator for IterMut<'_, K, V> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let slot = self.order.next()?;
+        Some(self.pair(slot))
+    }
+}
+
+impl<K, V> ExactSizeIterator for IterMut<'_, K, V> {}
+
+impl<K, V> FusedIterator for IterMut<'_, K, V> {}
+
+/// Itérateur sur les clés du cache, du plus récent au plus ancien
+///
+/// Obtenu avec [`Cache::keys`]
+pub struct Keys<'a, K, V> {
+    pub(super) inner: Iter<'a, K, V>,
+}
+
+impl<'a, K, V> Iterator for Keys<'a, K, V> {
+    type Item = &'a K;
+
+    fn next(&mut self) -> Option<&'a K> {
+        self.inner.next().map(|(key, _)| key)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<K, V> DoubleEndedIterator for Keys<'_, K, V> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.inner.next_back().map(|(key, _)| key)
+    }
+}
+
+impl<K, V> ExactSizeIterator for Keys<'_, K, V> {}
+
+impl<K, V> FusedIterator for Keys<'_, K, V> {}
+
+/// Itérateur sur les valeurs du cache, du plus récent au plus ancien
+///
+/// Obtenu avec [`Cache::values`]
+pub struct Values<'a, K, V> {
+    pub(super) inner: Iter<'a, K, V>,
+}
+
+impl<'a, K, V> Iterator for Values<'a, K, V> {
+    type Item = &'a V;
+
+    fn next(&mut self) -> Option<&'a V> {
+        self.inner.next().map(|(_, value)| value)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<K, V> DoubleEndedIterator for Values<'_, K, V> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.inner.next_back().map(|(_, value)| value)
+    }
+}
+
+impl<K, V> ExactSizeIterator for Values<'_, K, V> {}
+
+impl<K, V> FusedIterator for Values<'_, K, V> {}
+
+/// Itérateur qui consomme le cache, du plus récent au plus ancien
+///
+/// Obtenu avec `into_iter()` sur un [`Cache`]
+pub struct IntoIter<K, V, P = Lru>
+where
+    K: Eq + Hash,
+    P: EvictionPolicy,
+{
+    pub(super) cache: Cache<K, V, P>,
+}
+
+impl<K, V, P> Iterator for IntoIter<K, V, P>
+where
+    K: Eq + Hash,
+    P: EvictionPolicy,
+{
+    type Item = (K, V);
+
+    fn next(&mut self) -> Option<(K, V)> {
+        self.cache.pop_back()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.cache.cache_len();
+        (len, Some(len))
+    }
+}
+
+impl<K, V, P> DoubleEndedIterator for IntoIter<K, V, P>
+where
+    K: Eq + Hash,
+    P: EvictionPolicy,
+{
+    fn next_back(&mut self) -> Option<(K, V)> {
+        self.cache.pop_front()
+    }
+}
+
+impl<K, V, P> ExactSizeIterator for IntoIter<K, V, P> where K: Eq + Hash, P: EvictionPolicy {}
+
+impl<K, V, P> FusedIterator for IntoIter<K, V, P> where K: Eq + Hash, P: EvictionPolicy {}
+
+/// Itérateur qui vide le cache, du plus récent au plus ancien
+///
+/// Obtenu avec [`Cache::drain`]. Les éléments non parcourus sont retirés
+/// du cache lorsque l'itérateur est détruit
+pub struct Drain<'a, K, V, P = Lru>
+where
+    K: Eq + Hash,
+    P: EvictionPolicy,
+{
+    pub(super) cache: &'a mut Cache<K, V, P>,
+}
+
+impl<K, V, P> Iterator for Drain<'_, K, V, P>
+where
+    K: Eq + Hash,
+    P: EvictionPolicy,
+{
+    type Item = (K, V);
+
+    fn next(&mut self) -> Option<(K, V)> {
+        self.cache.pop_back()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.cache.cache_len();
+        (len, Some(len))
+    }
+}
+
+impl<K, V, P> DoubleEndedIterator for Drain<'_, K, V, P>
+where
+    K: Eq + Hash,
+    P: EvictionPolicy,
+{
+    fn next_back(&mut self) -> Option<(K, V)> {
+        self.cache.pop_front()
+    }
+}
+
+impl<K, V, P> ExactSizeIterator for Drain<'_, K, V, P> where K: Eq + Hash, P: EvictionPolicy {}
+
+impl<K, V, P> FusedIterator for Drain<'_, K, V, P> where K: Eq + Hash, P: EvictionPolicy {}
+
+impl<K, V, P> Drop for Drain<'_, K, V, P>
+where
+    K: Eq + Hash,
+    P: EvictionPolicy,
+{
+    fn drop(&mut self) {
+        for _ in self.by_ref() {}
+    }
+}
@@ -0,0 +1,195 @@
+/// Indice sentinelle qui représente l'absence de voisin
+const NIL: usize = usize::MAX;
+
+/// Liens d'un emplacement dans la liste
+#[derive(Clone, Copy)]
+struct Link {
+    prev: usize,
+    next: usize,
+    linked: bool,
+}
+
+const UNLINKED: Link = Link {
+    prev: NIL,
+    next: NIL,
+    linked: false,
+};
+
+/// Liste doublement chaînée d'indices d'emplacements
+///
+/// Les liens sont stockés dans un tableau indexé par l'emplacement, ce qui permet
+/// d'ajouter, de retirer et de déplacer un élément en O(1) sans allocation par nœud.
+/// Le début de la liste (`front`) contient l'élément le plus ancien
+/// et la fin (`back`) l'élément le plus récent.
+pub struct IndexList {
+    links: Vec<Link>,
+    head: usize,
+    tail: usize,
+    len: usize,
+}
+
+impl IndexList {
+    /// Créé une liste vide
+    pub fn new() -> Self {
+        Self {
+            links: Vec::new(),
+            head: NIL,
+            tail: NIL,
+            len: 0,
+        }
+    }
+
+    /// Nombre d'octets occupés par les liens d'un emplacement
+    pub(crate) const LINK_SIZE: usize = std::mem::size_of::<Link>();
+
+    /// Retourne le nombre d'octets alloués pour les liens de la liste
+    pub(crate) fn heap_size(&self) -> usize {
+        self.links.capacity() * Self::LINK_SIZE
+    }
+
+    /// Retourne le nombre d'emplacements dans la liste
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Indique si la liste est vide
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Indique si l'emplacement est dans la liste
+    pub fn contains(&self, slot: usize) -> bool {
+        self.links.get(slot).is_some_and(|link| link.linked)
+    }
+
+    /// Retourne l'emplacement le plus ancien de la liste
+    pub fn front(&self) -> Option<usize> {
+        (self.head != NIL).then_some(self.head)
+    }
+
+    /// Retourne l'emplacement le plus récent de la liste
+    pub fn back(&self) -> Option<usize> {
+        (self.tail != NIL).then_some(self.tail)
+    }
+
+    /// Retourne l'emplacement qui suit `slot` dans la liste (l'élément plus récent suivant)
+    ///
+    /// # Return
+    /// - `Option<usize>` : `None` si `slot` est le dernier emplacement ou n'est pas dans la liste
+    pub fn next(&self, slot: usize) -> Option<usize> {
+        self.links
+            .get(slot)
+            .filter(|link| link.linked && link.next != NIL)
+            .map(|link| link.next)
+    }
+
+    /// Retourne un itérateur sur les emplacements, du plus ancien au plus récent
+    pub fn iter(&self) -> Iter<'_> {
+        Iter {
+            list: self,
+            front: self.head,
+            back: self.tail,
+            remaining: self.len,
+        }
+    }
+
+    /// Ajoute un emplacement à la fin de la liste (élément le plus récent)
+    ///
+    /// L'emplacement ne doit pas déjà être dans la liste
+    pub fn push_back(&mut self, slot: usize) {
+        if slot >= self.links.len() {
+            self.links.resize(slot + 1, UNLINKED);
+        }
+        debug_assert!(!self.links[slot].linked);
+        self.links[slot] = Link {
+            prev: self.tail,
+            next: NIL,
+            linked: true,
+        };
+        if self.tail == NIL {
+            self.head = slot;
+        } else {
+            self.links[self.tail].next = slot;
+        }
+        self.tail = slot;
+        self.len += 1;
+    }
+
+    /// Retire un emplacement de la liste
+    ///
+    /// # Return
+    /// - `bool` : `true` si l'emplacement était dans la liste
+    pub fn remove(&mut self, slot: usize) -> bool {
+        match self.links.get(slot) {
+            Some(link) if link.linked => {
+                let Link { prev, next, .. } = *link;
+                if prev == NIL {
+                    self.head = next;
+                } else {
+                    self.links[prev].next = next;
+                }
+                if next == NIL {
+                    self.tail = prev;
+                } else {
+                    self.links[next].prev = prev;
+                }
+                self.links[slot] = UNLINKED;
+                self.len -= 1;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Déplace un emplacement à la fin de la liste
+    pub fn move_to_back(&mut self, slot: usize) {
+        if self.tail != slot && self.remove(slot) {
+            self.push_back(slot);
+        }
+    }
+}
+
+impl Default for IndexList {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Itérateur sur les emplacements d'une [`IndexList`], du plus ancien au plus récent
+#[derive(Clone)]
+pub struct Iter<'a> {
+    list: &'a IndexList,
+    front: usize,
+    back: usize,
+    remaining: usize,
+}
+
+impl Iterator for Iter<'_> {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<usize> {
+        if self.remaining == 0 {
+            return None;
+        }
+        let slot = self.front;
+        self.front = self.list.links[slot].next;
+        self.remaining -= 1;
+        Some(slot)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl DoubleEndedIterator for Iter<'_> {
+    fn next_back(&mut self) -> Option<usize> {
+        if self.remaining == 0 {
+            return None;
+        }
+        let slot = self.back;
+        self.back = self.list.links[slot].prev;
+        self.remaining -= 1;
+        Some(slot)
+    }
+}
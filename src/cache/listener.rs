@@ -0,0 +1,29 @@
+use std::sync::{Mutex, PoisonError};
+
+/// Raison pour laquelle un élément a quitté le cache
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RemovalCause {
+    /// L'élément le plus ancien a été retiré pour respecter la taille du cache
+    Capacity,
+    /// La valeur a été remplacée par une nouvelle valeur pour la même clé
+    Replaced,
+    /// L'élément a été retiré explicitement (par exemple avec `remove`)
+    Explicit,
+    /// L'élément a expiré
+    Expired,
+    /// Le cache a été vidé avec `clear`
+    Cleared,
+}
+
+/// Fonction appelée pour chaque élément qui quitte le cache
+///
+/// Le `Mutex` n'est jamais verrouillé, voir [`Cache`](crate::cache::Cache)
+pub(super) type RemovalListener<K, V> = Mutex<Box<dyn FnMut(&K, &V, RemovalCause) + Send>>;
+
+/// Prévient le listener, s'il existe, qu'un élément a quitté le cache
+pub(super) fn notify<K, V>(listener: &mut Option<RemovalListener<K, V>>, key: &K, value: &V, cause: RemovalCause) {
+    if let Some(listener) = listener {
+        let listener = listener.get_mut().unwrap_or_else(PoisonError::into_inner);
+        listener(key, value, cause);
+    }
+}
@@ -1,4 +1,20 @@
+#[allow(clippy::module_inception)]
 mod cache;
+mod clock;
+mod entry;
+mod heap_size;
+mod iter;
+mod list;
+mod listener;
+pub mod policy;
 pub mod trait_cache;
 
-pub use cache::Cache;
\ No newline at end of file
+pub use cache::{
+    ArcCache, Cache, ClockCache, FifoCache, GdsfCache, LfuCache, LirsCache, MruCache, RandomCache, S3FifoCache,
+    SieveCache, SlruCache, WTinyLfuCache,
+};
+pub use clock::{Clock, MockClock, SystemClock};
+pub use entry::{Entry, OccupiedEntry, VacantEntry};
+pub use heap_size::HeapSize;
+pub use iter::{Drain, IntoIter, Iter, IterMut, Keys, Values};
+pub use listener::RemovalCause;
@@ -0,0 +1,170 @@
+use crate::cache::policy::ghost::GhostList;
+use crate::cache::policy::{EvictionPolicy, IndexList};
+
+/// Origine de la clé en cours d'insertion, déterminée par `before_insert`
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Admission {
+    /// Clé inconnue, insérée dans T1
+    Recent,
+    /// Clé inconnue alors que T1 occupe tout le cache : le plus ancien de T1 est retiré sans fantôme
+    RecentFull,
+    /// Clé trouvée dans B1, insérée dans T2
+    RecentGhost,
+    /// Clé trouvée dans B2, insérée dans T2
+    FrequentGhost,
+}
+
+/// Politique ARC (Adaptive Replacement Cache)
+///
+/// Les éléments vus une seule fois sont dans la liste T1 et ceux vus plusieurs fois dans T2.
+/// Les hashs des clés retirées de T1 et T2 sont gardés dans les listes fantômes B1 et B2.
+/// Une clé retrouvée dans B1 augmente la place réservée à T1 (la `target`), une clé retrouvée
+/// dans B2 la diminue : la politique s'adapte seule entre récence et fréquence
+///
+/// # Exemples
+///
+/// ```
+/// use hashmap_cache::cache::ArcCache;
+/// use hashmap_cache::cache::trait_cache::TraitCache;
+///
+/// let mut cache = ArcCache::with_default_policy(2);
+///
+/// cache.put("A", 1); // T1 == [A]
+/// cache.put("B", 2); // T1 == [A,B]
+/// cache.get("A"); // T1 == [B], T2 == [A]
+/// cache.put("C", 3); // T1 == [C], T2 == [A], B1 == [B]
+///
+/// assert!(!cache.contains("B"));
+/// cache.put("B", 2); // "B" est dans B1 : la place réservée à T1 augmente
+/// assert_eq!(cache.policy().target(), 1);
+/// ```
+pub struct AdaptiveReplacement {
+    recent: IndexList,
+    frequent: IndexList,
+    recent_ghosts: GhostList,
+    frequent_ghosts: GhostList,
+    target: usize,
+    capacity: usize,
+    hashes: Vec<u64>,
+    admission: Option<Admission>,
+}
+
+impl AdaptiveReplacement {
+    /// Créé une politique ARC vide
+    pub fn new() -> Self {
+        Self {
+            recent: IndexList::new(),
+            frequent: IndexList::new(),
+            recent_ghosts: GhostList::new(),
+            frequent_ghosts: GhostList::new(),
+            target: 0,
+            capacity: 0,
+            hashes: Vec::new(),
+            admission: None,
+        }
+    }
+
+    /// Retourne la taille visée pour T1 (le paramètre `p` d'ARC)
+    pub fn target(&self) -> usize {
+        self.target
+    }
+
+    /// Retire les fantômes les plus anciens tant que |T1| + |B1| dépasse la taille du cache
+    /// ou que l'ensemble des listes dépasse deux fois la taille du cache
+    fn trim_ghosts(&mut self) {
+        while self.recent.len() + self.recent_ghosts.len() > self.capacity && self.recent_ghosts.pop_oldest().is_some() {}
+        while self.recent.len() + self.frequent.len() + self.recent_ghosts.len() + self.frequent_ghosts.len() > self.capacity.saturating_mul(2) {
+            if self.frequent_ghosts.pop_oldest().is_none() && self.recent_ghosts.pop_oldest().is_none() {
+                break;
+            }
+        }
+    }
+}
+
+impl Default for AdaptiveReplacement {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl EvictionPolicy for AdaptiveReplacement {
+    fn on_capacity(&mut self, capacity: usize) {
+        self.capacity = capacity;
+        self.target = self.target.min(capacity);
+        self.trim_ghosts();
+    }
+
+    fn before_insert(&mut self, hash: u64) {
+        let recent_ghosts = self.recent_ghosts.len();
+        let frequent_ghosts = self.frequent_ghosts.len();
+        let admission = if self.recent_ghosts.remove(hash) {
+            // La clé a été retirée trop tôt de T1 : T1 doit grandir
+            let delta = (frequent_ghosts / recent_ghosts).max(1);
+            self.target = self.target.saturating_add(delta).min(self.capacity);
+            Admission::RecentGhost
+        } else if self.frequent_ghosts.remove(hash) {
+            // La clé a été retirée trop tôt de T2 : T2 doit grandir
+            let delta = (recent_ghosts / frequent_ghosts).max(1);
+            self.target = self.target.saturating_sub(delta);
+            Admission::FrequentGhost
+        } else if self.recent.len() + recent_ghosts >= self.capacity {
+            if self.recent.len() < self.capacity {
+                self.recent_ghosts.pop_oldest();
+                Admission::Recent
+            } else {
+                Admission::RecentFull
+            }
+        } else {
+            if self.recent.len() + self.frequent.len() + recent_ghosts + frequent_ghosts >= self.capacity.saturating_mul(2) {
+                self.frequent_ghosts.pop_oldest();
+            }
+            Admission::Recent
+        };
+        self.admission = Some(admission);
+    }
+
+    fn on_insert(&mut self, slot: usize, hash: u64) {
+        if slot >= self.hashes.len() {
+            self.hashes.resize(slot + 1, 0);
+        }
+        self.hashes[slot] = hash;
+        match self.admission.take() {
+            Some(Admission::RecentGhost | Admission::FrequentGhost) => self.frequent.push_back(slot),
+            _ => self.recent.push_back(slot),
+        }
+        self.trim_ghosts();
+    }
+
+    fn on_access(&mut self, slot: usize) {
+        if self.recent.remove(slot) {
+            self.frequent.push_back(slot);
+        } else {
+            self.frequent.move_to_back(slot);
+        }
+    }
+
+    fn on_remove(&mut self, slot: usize) {
+        if !self.recent.remove(slot) {
+            self.frequent.remove(slot);
+        }
+    }
+
+    fn choose_victim(&mut self, _order: &IndexList) -> Option<usize> {
+        if self.admission == Some(Admission::RecentFull) {
+            return self.recent.front();
+        }
+        let recent = self.recent.len();
+        let from_frequent_ghosts = self.admission == Some(Admission::FrequentGhost);
+        let evict_recent = recent > 0
+            && (recent > self.target || (from_frequent_ghosts && recent == self.target) || self.frequent.is_empty());
+        if evict_recent {
+            let victim = self.recent.front()?;
+            self.recent_ghosts.push(self.hashes[victim]);
+            Some(victim)
+        } else {
+            let victim = self.frequent.front()?;
+            self.frequent_ghosts.push(self.hashes[victim]);
+            Some(victim)
+        }
+    }
+}
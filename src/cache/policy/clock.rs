@@ -0,0 +1,71 @@
+use crate::cache::policy::{EvictionPolicy, IndexList};
+
+/// Politique CLOCK (seconde chance)
+///
+/// Les éléments forment un anneau parcouru par une aiguille. Une lecture ne fait que marquer
+/// l'élément comme visité, sans modifier aucune liste. Pour faire de la place, l'aiguille
+/// retire le premier élément non visité qu'elle rencontre et efface la marque des éléments
+/// visités qu'elle dépasse, qui ont ainsi une seconde chance
+///
+/// # Exemples
+///
+/// ```
+/// use hashmap_cache::cache::ClockCache;
+/// use hashmap_cache::cache::trait_cache::TraitCache;
+///
+/// let mut cache = ClockCache::with_default_policy(3);
+///
+/// cache.put("A", 1); // aiguille -> A, B, C
+/// cache.put("B", 2);
+/// cache.put("C", 3);
+/// cache.get("A"); // "A" est marqué
+///
+/// cache.put("D", 4); // la marque de "A" est effacée, "B" est retiré
+/// assert!(cache.contains("A"));
+/// assert!(!cache.contains("B"));
+/// ```
+#[derive(Default)]
+pub struct Clock {
+    ring: IndexList,
+    visited: Vec<bool>,
+}
+
+impl Clock {
+    /// Créé une politique CLOCK vide
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl EvictionPolicy for Clock {
+    const PROMOTE_ON_ACCESS: bool = false;
+
+    fn on_insert(&mut self, slot: usize, _hash: u64) {
+        if slot >= self.visited.len() {
+            self.visited.resize(slot + 1, false);
+        }
+        self.visited[slot] = false;
+        // Le nouvel élément est placé juste derrière l'aiguille
+        self.ring.push_back(slot);
+    }
+
+    fn on_access(&mut self, slot: usize) {
+        self.visited[slot] = true;
+    }
+
+    fn on_remove(&mut self, slot: usize) {
+        self.ring.remove(slot);
+    }
+
+    fn choose_victim(&mut self, _order: &IndexList) -> Option<usize> {
+        // L'aiguille est toujours en tête de l'anneau : dépasser un élément le place en fin
+        while let Some(slot) = self.ring.front() {
+            if !self.visited[slot] {
+                return Some(slot);
+            }
+            self.visited[slot] = false;
+            self.ring.move_to_back(slot);
+        }
+        None
+    }
+}
@@ -0,0 +1,37 @@
+use crate::cache::policy::{EvictionPolicy, IndexList};
+
+/// Politique FIFO (First In, First Out) : retire l'élément inséré le plus tôt
+///
+/// Une lecture ou une mise à jour ne déplace pas l'élément, l'ordre du cache est l'ordre d'insertion
+///
+/// # Exemples
+///
+/// ```
+/// use hashmap_cache::cache::FifoCache;
+/// use hashmap_cache::cache::trait_cache::TraitCache;
+///
+/// let mut cache = FifoCache::with_default_policy(2);
+///
+/// cache.put("A", 1); // [A]
+/// cache.put("B", 2); // [A,B]
+/// cache.get("A"); // [A,B]
+/// cache.put("C", 3); // [B,C]
+///
+/// assert!(!cache.contains("A"));
+/// ```
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Fifo;
+
+impl EvictionPolicy for Fifo {
+    const PROMOTE_ON_ACCESS: bool = false;
+
+    fn on_insert(&mut self, _slot: usize, _hash: u64) {}
+
+    fn on_access(&mut self, _slot: usize) {}
+
+    fn on_remove(&mut self, _slot: usize) {}
+
+    fn choose_victim(&mut self, order: &IndexList) -> Option<usize> {
+        order.front()
+    }
+}
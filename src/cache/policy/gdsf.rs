@@ -0,0 +1,117 @@
+use std::collections::BTreeSet;
+use crate::cache::policy::{EvictionPolicy, IndexList};
+
+/// Taille, coût et fréquence d'un élément suivi par GDSF
+#[derive(Clone, Copy)]
+struct Item {
+    size: u64,
+    cost: f64,
+    frequency: u64,
+    priority: f64,
+}
+
+/// Politique GDSF (Greedy-Dual-Size-Frequency)
+///
+/// Chaque élément a une taille et un coût de recalcul, donnés par
+/// [`Cache::put_with_cost`](crate::cache::Cache::put_with_cost) (1 et 1 avec `put`).
+/// Sa priorité vaut `L + fréquence * coût / taille`, où `L` (l'inflation) est la priorité
+/// du dernier élément retiré. L'élément de plus faible priorité est retiré : un petit élément
+/// coûteux survit à un gros élément peu coûteux, et l'inflation fait vieillir les éléments
+/// qui ne sont plus utilisés
+///
+/// # Exemples
+///
+/// ```
+/// use hashmap_cache::cache::GdsfCache;
+/// use hashmap_cache::cache::trait_cache::TraitCache;
+///
+/// let mut cache = GdsfCache::with_default_policy(2);
+///
+/// cache.put_with_cost("petit", 1, 10, 50.0); // priorité 5
+/// cache.put_with_cost("gros", 2, 1000, 50.0); // priorité 0,05
+/// cache.put_with_cost("autre", 3, 10, 1.0); // "gros" est retiré
+///
+/// assert!(cache.contains("petit"));
+/// assert!(!cache.contains("gros"));
+/// assert_eq!(cache.policy().inflation(), 0.05);
+/// ```
+pub struct Gdsf {
+    items: Vec<Option<Item>>,
+    queue: BTreeSet<(u64, usize)>,
+    inflation: f64,
+    pending: Option<(u64, f64)>,
+}
+
+impl Gdsf {
+    /// Créé une politique GDSF vide
+    pub fn new() -> Self {
+        Self {
+            items: Vec::new(),
+            queue: BTreeSet::new(),
+            inflation: 0.0,
+            pending: None,
+        }
+    }
+
+    /// Retourne l'inflation `L`, la priorité du dernier élément retiré pour faire de la place
+    pub fn inflation(&self) -> f64 {
+        self.inflation
+    }
+
+    /// Donne la taille et le coût du prochain élément inséré ou mis à jour
+    pub(crate) fn set_pending(&mut self, pending: Option<(u64, f64)>) {
+        self.pending = pending;
+    }
+
+    /// Calcule la priorité d'un élément et le range dans la file des priorités
+    fn schedule(&mut self, slot: usize, mut item: Item) {
+        item.priority = self.inflation + item.frequency as f64 * item.cost / item.size as f64;
+        // Une priorité positive garde son ordre une fois convertie en bits
+        self.queue.insert((item.priority.to_bits(), slot));
+        self.items[slot] = Some(item);
+    }
+
+    /// Retire un élément de la file des priorités
+    fn unschedule(&mut self, slot: usize) -> Option<Item> {
+        let item = self.items.get_mut(slot)?.take()?;
+        self.queue.remove(&(item.priority.to_bits(), slot));
+        Some(item)
+    }
+}
+
+impl Default for Gdsf {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl EvictionPolicy for Gdsf {
+    fn on_insert(&mut self, slot: usize, _hash: u64) {
+        if slot >= self.items.len() {
+            self.items.resize(slot + 1, None);
+        }
+        let (size, cost) = self.pending.take().unwrap_or((1, 1.0));
+        self.schedule(slot, Item { size, cost, frequency: 1, priority: 0.0 });
+    }
+
+    fn on_access(&mut self, slot: usize) {
+        if let Some(mut item) = self.unschedule(slot) {
+            if let Some((size, cost)) = self.pending.take() {
+                item.size = size;
+                item.cost = cost;
+            }
+            item.frequency = item.frequency.saturating_add(1);
+            self.schedule(slot, item);
+        }
+    }
+
+    fn on_remove(&mut self, slot: usize) {
+        self.unschedule(slot);
+    }
+
+    fn choose_victim(&mut self, _order: &IndexList) -> Option<usize> {
+        let &(priority, slot) = self.queue.first()?;
+        self.inflation = f64::from_bits(priority);
+        Some(slot)
+    }
+}
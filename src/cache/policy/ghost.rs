@@ -0,0 +1,66 @@
+use std::collections::{HashMap, VecDeque};
+
+/// File des hashs de clés récemment retirées du cache (« fantômes »)
+///
+/// Seul le hash de la clé est conservé : la file mémorise l'historique sans garder
+/// les clés ni les valeurs. L'ajout, la recherche, le retrait et le retrait du plus ancien
+/// sont en O(1) amorti, les retraits au milieu de la file étant faits paresseusement
+pub(crate) struct GhostList {
+    entries: HashMap<u64, u64>,
+    queue: VecDeque<(u64, u64)>,
+    next_sequence: u64,
+}
+
+impl GhostList {
+    /// Créé une file vide
+    pub(crate) fn new() -> Self {
+        Self {
+            entries: HashMap::new(),
+            queue: VecDeque::new(),
+            next_sequence: 0,
+        }
+    }
+
+    /// Retourne le nombre de hashs dans la file
+    pub(crate) fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Ajoute un hash à la fin de la file (le plus récent)
+    pub(crate) fn push(&mut self, hash: u64) {
+        let sequence = self.next_sequence;
+        self.next_sequence += 1;
+        self.entries.insert(hash, sequence);
+        self.queue.push_back((hash, sequence));
+        self.compact();
+    }
+
+    /// Retire un hash de la file
+    ///
+    /// # Return
+    /// - `bool` : `true` si le hash était dans la file
+    pub(crate) fn remove(&mut self, hash: u64) -> bool {
+        let removed = self.entries.remove(&hash).is_some();
+        self.compact();
+        removed
+    }
+
+    /// Retire et retourne le hash le plus ancien de la file
+    pub(crate) fn pop_oldest(&mut self) -> Option<u64> {
+        while let Some((hash, sequence)) = self.queue.pop_front() {
+            if self.entries.get(&hash) == Some(&sequence) {
+                self.entries.remove(&hash);
+                return Some(hash);
+            }
+        }
+        None
+    }
+
+    /// Supprime les hashs retirés paresseusement lorsqu'ils occupent plus de la moitié de la file
+    fn compact(&mut self) {
+        if self.queue.len() > 2 * self.entries.len() + 16 {
+            let entries = &self.entries;
+            self.queue.retain(|(hash, sequence)| entries.get(hash) == Some(sequence));
+        }
+    }
+}
@@ -0,0 +1,181 @@
+use crate::cache::policy::{EvictionPolicy, IndexList};
+
+/// Indice sentinelle qui représente l'absence de voisin
+const NIL: usize = usize::MAX;
+
+/// Groupe des emplacements qui ont la même fréquence d'utilisation
+///
+/// Les groupes sont chaînés par fréquence croissante, les emplacements d'un groupe
+/// sont chaînés du moins récent au plus récent
+struct Bucket {
+    frequency: u64,
+    head: usize,
+    tail: usize,
+    prev: usize,
+    next: usize,
+}
+
+/// Position d'un emplacement dans son groupe
+#[derive(Clone, Copy)]
+struct Item {
+    bucket: usize,
+    prev: usize,
+    next: usize,
+}
+
+/// Politique LFU (Least Frequently Used) : retire l'élément utilisé le moins souvent
+///
+/// En cas d'égalité, l'élément utilisé le moins récemment parmi les moins fréquents est retiré.
+/// Les fréquences sont rangées dans des groupes chaînés, l'insertion, l'accès, le retrait
+/// et le choix de l'élément à retirer sont donc tous en O(1)
+///
+/// # Exemples
+///
+/// ```
+/// use hashmap_cache::cache::LfuCache;
+/// use hashmap_cache::cache::trait_cache::TraitCache;
+///
+/// let mut cache = LfuCache::with_default_policy(2);
+///
+/// cache.put("A", 1); // A:1
+/// cache.put("B", 2); // A:1, B:1
+/// cache.get("A"); // A:2, B:1
+/// cache.put("C", 3); // A:2, C:1 ("B" est le moins utilisé)
+///
+/// assert!(cache.contains("A"));
+/// assert!(!cache.contains("B"));
+/// ```
+pub struct Lfu {
+    items: Vec<Item>,
+    buckets: Vec<Bucket>,
+    free_buckets: Vec<usize>,
+    first: usize,
+}
+
+impl Lfu {
+    /// Créé une politique LFU vide
+    pub fn new() -> Self {
+        Self {
+            items: Vec::new(),
+            buckets: Vec::new(),
+            free_buckets: Vec::new(),
+            first: NIL,
+        }
+    }
+
+    /// Créé un groupe de fréquence `frequency` placé juste après le groupe `prev`
+    /// (ou en tête si `prev` vaut `NIL`)
+    fn create_bucket(&mut self, frequency: u64, prev: usize) -> usize {
+        let next = if prev == NIL { self.first } else { self.buckets[prev].next };
+        let bucket = Bucket { frequency, head: NIL, tail: NIL, prev, next };
+        let index = match self.free_buckets.pop() {
+            Some(index) => {
+                self.buckets[index] = bucket;
+                index
+            }
+            None => {
+                self.buckets.push(bucket);
+                self.buckets.len() - 1
+            }
+        };
+        if prev == NIL {
+            self.first = index;
+        } else {
+            self.buckets[prev].next = index;
+        }
+        if next != NIL {
+            self.buckets[next].prev = index;
+        }
+        index
+    }
+
+    /// Retire un groupe vide de la chaîne des fréquences
+    fn remove_bucket(&mut self, index: usize) {
+        let Bucket { prev, next, .. } = self.buckets[index];
+        if prev == NIL {
+            self.first = next;
+        } else {
+            self.buckets[prev].next = next;
+        }
+        if next != NIL {
+            self.buckets[next].prev = prev;
+        }
+        self.free_buckets.push(index);
+    }
+
+    /// Ajoute un emplacement à la fin (élément le plus récent) d'un groupe
+    fn push_item(&mut self, slot: usize, bucket: usize) {
+        if slot >= self.items.len() {
+            self.items.resize(slot + 1, Item { bucket: NIL, prev: NIL, next: NIL });
+        }
+        let tail = self.buckets[bucket].tail;
+        self.items[slot] = Item { bucket, prev: tail, next: NIL };
+        if tail == NIL {
+            self.buckets[bucket].head = slot;
+        } else {
+            self.items[tail].next = slot;
+        }
+        self.buckets[bucket].tail = slot;
+    }
+
+    /// Retire un emplacement de son groupe et retourne le groupe
+    fn unlink_item(&mut self, slot: usize) -> usize {
+        let Item { bucket, prev, next } = self.items[slot];
+        if prev == NIL {
+            self.buckets[bucket].head = next;
+        } else {
+            self.items[prev].next = next;
+        }
+        if next == NIL {
+            self.buckets[bucket].tail = prev;
+        } else {
+            self.items[next].prev = prev;
+        }
+        self.items[slot].bucket = NIL;
+        bucket
+    }
+}
+
+impl Default for Lfu {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl EvictionPolicy for Lfu {
+    fn on_insert(&mut self, slot: usize, _hash: u64) {
+        let bucket = if self.first != NIL && self.buckets[self.first].frequency == 1 {
+            self.first
+        } else {
+            self.create_bucket(1, NIL)
+        };
+        self.push_item(slot, bucket);
+    }
+
+    fn on_access(&mut self, slot: usize) {
+        let bucket = self.items[slot].bucket;
+        let frequency = self.buckets[bucket].frequency.saturating_add(1);
+        let next = self.buckets[bucket].next;
+        let target = if next != NIL && self.buckets[next].frequency == frequency {
+            next
+        } else {
+            self.create_bucket(frequency, bucket)
+        };
+        self.unlink_item(slot);
+        self.push_item(slot, target);
+        if self.buckets[bucket].head == NIL {
+            self.remove_bucket(bucket);
+        }
+    }
+
+    fn on_remove(&mut self, slot: usize) {
+        let bucket = self.unlink_item(slot);
+        if self.buckets[bucket].head == NIL {
+            self.remove_bucket(bucket);
+        }
+    }
+
+    fn choose_victim(&mut self, _order: &IndexList) -> Option<usize> {
+        (self.first != NIL).then(|| self.buckets[self.first].head)
+    }
+}
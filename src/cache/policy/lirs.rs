@@ -0,0 +1,262 @@
+use std::collections::HashMap;
+use crate::cache::policy::{EvictionPolicy, IndexList};
+
+/// Indice sentinelle qui représente l'absence d'emplacement
+const NIL: usize = usize::MAX;
+
+/// Statut d'une clé suivie par LIRS
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Status {
+    /// Clé résidente à faible distance de réutilisation
+    Lir,
+    /// Clé résidente à forte distance de réutilisation, candidate au retrait
+    Hir,
+    /// Clé retirée du cache dont seul le hash est conservé
+    NonResident,
+}
+
+/// Clé suivie par LIRS, résidente ou non
+///
+/// Les clés non résidentes sont toujours dans S : l'élagage les oublie en atteignant le fond de S
+struct Record {
+    hash: u64,
+    slot: usize,
+    status: Status,
+}
+
+/// Politique LIRS (Low Inter-reference Recency Set)
+///
+/// Les clés relues à courte distance forment l'ensemble LIR (99 % du cache par défaut), les autres
+/// sont HIR. La pile S suit la récence des clés LIR, des clés HIR résidentes et des clés HIR
+/// déjà retirées (non résidentes, dont seul le hash est gardé) ; son fond est toujours une clé
+/// LIR (élagage). La file Q contient les clés HIR résidentes, c'est toujours son plus ancien
+/// élément qui est retiré. Une clé HIR relue alors qu'elle est encore dans S devient LIR
+/// et la clé LIR du fond de S redevient HIR : contrairement à LRU, un parcours en boucle
+/// plus grand que le cache ne chasse pas les clés LIR. Le nombre de clés non résidentes
+/// est borné par la taille du cache
+///
+/// # Exemples
+///
+/// ```
+/// use hashmap_cache::cache::LirsCache;
+/// use hashmap_cache::cache::policy::Lirs;
+/// use hashmap_cache::cache::trait_cache::TraitCache;
+///
+/// let mut cache = LirsCache::with_policy(3, Lirs::new(0.34));
+///
+/// // Parcours en boucle de 4 clés dans un cache de 3
+/// for _ in 0..3 {
+///     for key in 0..4 {
+///         cache.get_or_insert_with(key, || key).unwrap();
+///     }
+/// }
+///
+/// // Les clés LIR restent dans le cache, seule la place HIR change de clé
+/// assert!(cache.contains(&0));
+/// assert!(cache.contains(&1));
+/// ```
+pub struct Lirs {
+    records: Vec<Record>,
+    free_records: Vec<usize>,
+    slot_records: Vec<usize>,
+    stack: IndexList,
+    queue: IndexList,
+    ghosts: IndexList,
+    ghost_index: HashMap<u64, usize>,
+    lir_count: usize,
+    hir_ratio: f64,
+    lir_capacity: usize,
+    ghost_capacity: usize,
+}
+
+impl Lirs {
+    /// Créé une politique LIRS dont les clés HIR résidentes occupent `hir_ratio` du cache
+    ///
+    /// Les clés HIR résidentes ont toujours au moins une place
+    ///
+    /// # Arguments
+    /// - `hir_ratio` : La part du cache réservée aux clés HIR résidentes, entre 0 et 1
+    ///
+    /// # Panics
+    /// Si `hir_ratio` n'est pas entre 0 et 1
+    pub fn new(hir_ratio: f64) -> Self {
+        assert!((0.0..=1.0).contains(&hir_ratio), "la part HIR doit être entre 0 et 1");
+        Self {
+            records: Vec::new(),
+            free_records: Vec::new(),
+            slot_records: Vec::new(),
+            stack: IndexList::new(),
+            queue: IndexList::new(),
+            ghosts: IndexList::new(),
+            ghost_index: HashMap::new(),
+            lir_count: 0,
+            hir_ratio,
+            lir_capacity: 0,
+            ghost_capacity: 0,
+        }
+    }
+
+    /// Range une nouvelle clé et retourne son indice
+    fn allocate(&mut self, record: Record) -> usize {
+        match self.free_records.pop() {
+            Some(id) => {
+                self.records[id] = record;
+                id
+            }
+            None => {
+                self.records.push(record);
+                self.records.len() - 1
+            }
+        }
+    }
+
+    /// Oublie une clé non résidente
+    fn forget(&mut self, id: usize) {
+        self.stack.remove(id);
+        self.ghosts.remove(id);
+        if self.ghost_index.get(&self.records[id].hash) == Some(&id) {
+            self.ghost_index.remove(&self.records[id].hash);
+        }
+        self.free_records.push(id);
+    }
+
+    /// Retire du fond de S les clés HIR, pour que le fond de S soit une clé LIR
+    fn prune(&mut self) {
+        while let Some(id) = self.stack.front() {
+            match self.records[id].status {
+                Status::Lir => break,
+                Status::Hir => {
+                    self.stack.remove(id);
+                }
+                Status::NonResident => self.forget(id),
+            }
+        }
+    }
+
+    /// Fait redevenir HIR la clé LIR du fond de S
+    fn demote_bottom(&mut self) {
+        self.prune();
+        if let Some(id) = self.stack.front() {
+            self.stack.remove(id);
+            self.records[id].status = Status::Hir;
+            self.queue.push_back(self.records[id].slot);
+            self.lir_count -= 1;
+            self.prune();
+        }
+    }
+
+    /// Oublie les clés non résidentes les plus anciennes tant qu'elles dépassent la taille du cache
+    fn trim_ghosts(&mut self) {
+        while self.ghosts.len() > self.ghost_capacity {
+            match self.ghosts.front() {
+                Some(id) => self.forget(id),
+                None => break,
+            }
+        }
+    }
+}
+
+impl Default for Lirs {
+    /// Clés HIR résidentes sur 1 % du cache
+    fn default() -> Self {
+        Self::new(0.01)
+    }
+}
+
+impl EvictionPolicy for Lirs {
+    fn on_capacity(&mut self, capacity: usize) {
+        let hir_capacity = ((capacity as f64 * self.hir_ratio) as usize).max(1);
+        self.lir_capacity = capacity.saturating_sub(hir_capacity);
+        self.ghost_capacity = capacity;
+        while self.lir_count > self.lir_capacity {
+            self.demote_bottom();
+        }
+        self.trim_ghosts();
+    }
+
+    fn on_insert(&mut self, slot: usize, hash: u64) {
+        if slot >= self.slot_records.len() {
+            self.slot_records.resize(slot + 1, NIL);
+        }
+        let id = match self.ghost_index.remove(&hash) {
+            Some(id) => {
+                // Clé non résidente encore dans S : sa distance de réutilisation est courte
+                self.ghosts.remove(id);
+                self.records[id] = Record { hash, slot, status: Status::Lir };
+                self.stack.move_to_back(id);
+                self.lir_count += 1;
+                id
+            }
+            None => {
+                let status = if self.lir_count < self.lir_capacity { Status::Lir } else { Status::Hir };
+                let id = self.allocate(Record { hash, slot, status });
+                self.stack.push_back(id);
+                if status == Status::Lir {
+                    self.lir_count += 1;
+                } else {
+                    self.queue.push_back(slot);
+                }
+                id
+            }
+        };
+        self.slot_records[slot] = id;
+        if self.lir_count > self.lir_capacity {
+            self.demote_bottom();
+        }
+    }
+
+    fn on_access(&mut self, slot: usize) {
+        let id = self.slot_records[slot];
+        match self.records[id].status {
+            Status::Lir => {
+                self.stack.move_to_back(id);
+                self.prune();
+            }
+            Status::Hir if self.stack.contains(id) => {
+                self.stack.move_to_back(id);
+                self.queue.remove(slot);
+                self.records[id].status = Status::Lir;
+                self.lir_count += 1;
+                if self.lir_count > self.lir_capacity {
+                    self.demote_bottom();
+                }
+            }
+            _ => {
+                self.stack.push_back(id);
+                self.queue.move_to_back(slot);
+            }
+        }
+    }
+
+    fn on_remove(&mut self, slot: usize) {
+        let id = std::mem::replace(&mut self.slot_records[slot], NIL);
+        match self.records[id].status {
+            Status::Lir => {
+                self.stack.remove(id);
+                self.lir_count -= 1;
+                self.free_records.push(id);
+                self.prune();
+            }
+            _ if self.stack.contains(id) => {
+                // La clé reste dans S sans valeur : elle devient non résidente
+                self.queue.remove(slot);
+                let hash = self.records[id].hash;
+                self.records[id].status = Status::NonResident;
+                self.records[id].slot = NIL;
+                if let Some(previous) = self.ghost_index.insert(hash, id) {
+                    self.forget(previous);
+                }
+                self.ghosts.push_back(id);
+                self.trim_ghosts();
+            }
+            _ => {
+                self.queue.remove(slot);
+                self.free_records.push(id);
+            }
+        }
+    }
+
+    fn choose_victim(&mut self, _order: &IndexList) -> Option<usize> {
+        self.queue.front().or_else(|| self.stack.front().map(|id| self.records[id].slot))
+    }
+}
@@ -0,0 +1,19 @@
+use crate::cache::policy::{EvictionPolicy, IndexList};
+
+/// Politique LRU (Least Recently Used) : retire l'élément utilisé le moins récemment
+///
+/// L'ordre d'utilisation est déjà tenu par le cache, la politique n'a donc rien à mémoriser
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Lru;
+
+impl EvictionPolicy for Lru {
+    fn on_insert(&mut self, _slot: usize, _hash: u64) {}
+
+    fn on_access(&mut self, _slot: usize) {}
+
+    fn on_remove(&mut self, _slot: usize) {}
+
+    fn choose_victim(&mut self, order: &IndexList) -> Option<usize> {
+        order.front()
+    }
+}
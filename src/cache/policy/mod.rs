@@ -0,0 +1,76 @@
+mod arc;
+mod clock;
+mod fifo;
+mod gdsf;
+mod ghost;
+mod lfu;
+mod lirs;
+mod lru;
+mod mru;
+mod random;
+mod s3fifo;
+mod sieve;
+mod sketch;
+mod slru;
+mod tinylfu;
+
+pub use crate::cache::list::IndexList;
+pub use arc::AdaptiveReplacement;
+pub use clock::Clock;
+pub use fifo::Fifo;
+pub use gdsf::Gdsf;
+pub use lfu::Lfu;
+pub use lirs::Lirs;
+pub use lru::Lru;
+pub use mru::Mru;
+pub use random::RandomEviction;
+pub use s3fifo::S3Fifo;
+pub use sieve::Sieve;
+pub use slru::SegmentedLru;
+pub use tinylfu::WTinyLfu;
+
+/// Politique d'éviction utilisée par un [`Cache`](crate::cache::Cache)
+///
+/// Le cache range les éléments dans des emplacements numérotés et prévient la politique
+/// de chaque insertion, accès et retrait. Lorsqu'il doit faire de la place, il lui demande
+/// quel emplacement retirer. Toutes les méthodes sont appelées en O(1) par le cache,
+/// la complexité d'une opération du cache est donc celle de la politique
+///
+pub trait EvictionPolicy {
+    /// Indique si le cache place un élément lu ou mis à jour à la fin de son ordre d'utilisation
+    ///
+    /// Les politiques qui tiennent leur propre ordre (CLOCK, SIEVE...) le désactivent pour qu'une
+    /// lecture ne modifie aucune liste. L'ordre du cache, et donc celui des itérateurs,
+    /// devient alors l'ordre d'insertion
+    const PROMOTE_ON_ACCESS: bool = true;
+
+    /// La taille maximale du cache est fixée à `capacity`, à la création du cache
+    /// puis à chaque appel de `set_capacity`
+    fn on_capacity(&mut self, _capacity: usize) {}
+
+    /// Une clé absente du cache, de hash `hash`, va être insérée
+    ///
+    /// Appelée avant que le cache ne fasse de la place avec `choose_victim`
+    fn before_insert(&mut self, _hash: u64) {}
+
+    /// Un nouvel élément, dont la clé a pour hash `hash`, a été rangé dans l'emplacement `slot`
+    fn on_insert(&mut self, slot: usize, hash: u64);
+
+    /// L'élément de l'emplacement `slot` a été lu ou mis à jour
+    fn on_access(&mut self, slot: usize);
+
+    /// L'élément de l'emplacement `slot` a quitté le cache
+    fn on_remove(&mut self, slot: usize);
+
+    /// Retourne l'emplacement à retirer pour faire de la place
+    ///
+    /// L'emplacement retourné est retiré du cache juste après, `on_remove` est alors appelée
+    ///
+    /// # Arguments
+    /// - `order` : L'ordre d'utilisation du cache, du plus ancien au plus récent
+    ///
+    /// # Return
+    /// - `Option<usize>` : L'emplacement à retirer, `None` seulement si le cache est vide
+    ///   (le cache arrête alors de retirer des éléments, même s'il dépasse sa taille)
+    fn choose_victim(&mut self, order: &IndexList) -> Option<usize>;
+}
@@ -0,0 +1,37 @@
+use crate::cache::policy::{EvictionPolicy, IndexList};
+
+/// Politique MRU (Most Recently Used) : retire l'élément utilisé le plus récemment
+///
+/// Adaptée aux parcours en boucle plus grands que le cache, où l'élément qui vient d'être
+/// utilisé est celui qui sera relu le plus tard. Le nouvel élément n'est jamais retiré
+/// à sa propre insertion : la place est faite avant qu'il n'entre dans le cache
+///
+/// # Exemples
+///
+/// ```
+/// use hashmap_cache::cache::MruCache;
+/// use hashmap_cache::cache::trait_cache::TraitCache;
+///
+/// let mut cache = MruCache::with_default_policy(2);
+///
+/// cache.put("A", 1); // [A]
+/// cache.put("B", 2); // [A,B]
+/// cache.get("A"); // [B,A]
+/// cache.put("C", 3); // [B,C]
+///
+/// assert!(!cache.contains("A"));
+/// ```
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Mru;
+
+impl EvictionPolicy for Mru {
+    fn on_insert(&mut self, _slot: usize, _hash: u64) {}
+
+    fn on_access(&mut self, _slot: usize) {}
+
+    fn on_remove(&mut self, _slot: usize) {}
+
+    fn choose_victim(&mut self, order: &IndexList) -> Option<usize> {
+        order.back()
+    }
+}
@@ -0,0 +1,89 @@
+use crate::cache::policy::{EvictionPolicy, IndexList};
+
+/// Indice sentinelle qui représente l'absence de position
+const NIL: usize = usize::MAX;
+
+/// Politique d'éviction aléatoire : retire un élément tiré au hasard
+///
+/// Le tirage est fait par un générateur pseudo-aléatoire (SplitMix64) initialisé avec une graine :
+/// deux caches créés avec la même graine et utilisés de la même façon retirent les mêmes éléments
+///
+/// # Exemples
+///
+/// ```
+/// use hashmap_cache::cache::RandomCache;
+/// use hashmap_cache::cache::policy::RandomEviction;
+/// use hashmap_cache::cache::trait_cache::TraitCache;
+///
+/// let mut first = RandomCache::with_policy(2, RandomEviction::new(42));
+/// let mut second = RandomCache::with_policy(2, RandomEviction::new(42));
+///
+/// for key in ["A", "B", "C", "D"] {
+///     assert_eq!(first.put(key, 0), second.put(key, 0));
+/// }
+/// assert_eq!(first.len(), 2);
+/// ```
+#[derive(Debug, Clone)]
+pub struct RandomEviction {
+    state: u64,
+    slots: Vec<usize>,
+    positions: Vec<usize>,
+}
+
+impl RandomEviction {
+    /// Créé une politique aléatoire initialisée avec la graine `seed`
+    ///
+    /// # Arguments
+    /// - `seed` : La graine du générateur pseudo-aléatoire
+    pub fn new(seed: u64) -> Self {
+        Self {
+            state: seed,
+            slots: Vec::new(),
+            positions: Vec::new(),
+        }
+    }
+
+    /// Retourne le prochain nombre pseudo-aléatoire
+    fn next_random(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+}
+
+impl Default for RandomEviction {
+    /// Graine 0
+    fn default() -> Self {
+        Self::new(0)
+    }
+}
+
+impl EvictionPolicy for RandomEviction {
+    fn on_insert(&mut self, slot: usize, _hash: u64) {
+        if slot >= self.positions.len() {
+            self.positions.resize(slot + 1, NIL);
+        }
+        self.positions[slot] = self.slots.len();
+        self.slots.push(slot);
+    }
+
+    fn on_access(&mut self, _slot: usize) {}
+
+    fn on_remove(&mut self, slot: usize) {
+        let position = std::mem::replace(&mut self.positions[slot], NIL);
+        self.slots.swap_remove(position);
+        if let Some(&moved) = self.slots.get(position) {
+            self.positions[moved] = position;
+        }
+    }
+
+    fn choose_victim(&mut self, _order: &IndexList) -> Option<usize> {
+        if self.slots.is_empty() {
+            return None;
+        }
+        let index = (self.next_random() % self.slots.len() as u64) as usize;
+        Some(self.slots[index])
+    }
+}
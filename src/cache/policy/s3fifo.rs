@@ -0,0 +1,157 @@
+use crate::cache::policy::ghost::GhostList;
+use crate::cache::policy::{EvictionPolicy, IndexList};
+
+/// Valeur maximale du compteur d'accès d'un élément
+const MAX_FREQUENCY: u8 = 3;
+
+/// Politique S3-FIFO
+///
+/// Le cache est partagé entre une petite file S (10 % du cache par défaut) et une file
+/// principale M, complétées par une file fantôme G qui garde les hashs des clés retirées de S.
+/// Une lecture ne fait qu'incrémenter le compteur de l'élément (jusqu'à 3), aucune file n'est
+/// modifiée. Les nouveaux éléments entrent dans S, ou directement dans M si leur clé est dans G.
+/// Quand S atteint sa taille, son plus ancien élément passe dans M s'il a été relu, sinon
+/// il est retiré et sa clé rejoint G. Le plus ancien élément de M est retiré s'il n'a pas été
+/// relu, sinon il est replacé en fin de M avec un compteur diminué de un
+///
+/// # Exemples
+///
+/// ```
+/// use hashmap_cache::cache::S3FifoCache;
+/// use hashmap_cache::cache::policy::S3Fifo;
+/// use hashmap_cache::cache::trait_cache::TraitCache;
+///
+/// let mut cache = S3FifoCache::with_policy(2, S3Fifo::new(0.5));
+///
+/// cache.put("A", 1); // S == [A]
+/// cache.put("B", 2); // S == [A,B]
+/// cache.get("A");
+/// cache.put("C", 3); // "A" a été relu : S == [C], M == [A], G == [B]
+/// assert!(!cache.contains("B"));
+///
+/// cache.put("B", 2); // "B" est dans G : S == [], M == [A,B], G == [C]
+/// assert!(cache.contains("A"));
+/// assert!(!cache.contains("C"));
+/// ```
+pub struct S3Fifo {
+    small: IndexList,
+    main: IndexList,
+    ghosts: GhostList,
+    frequencies: Vec<u8>,
+    hashes: Vec<u64>,
+    small_ratio: f64,
+    small_capacity: usize,
+    ghost_capacity: usize,
+    from_ghost: bool,
+}
+
+impl S3Fifo {
+    /// Créé une politique S3-FIFO dont la petite file occupe `small_ratio` du cache
+    ///
+    /// La petite file contient toujours au moins un élément
+    ///
+    /// # Arguments
+    /// - `small_ratio` : La part du cache réservée à la petite file, entre 0 et 1
+    ///
+    /// # Panics
+    /// Si `small_ratio` n'est pas entre 0 et 1
+    pub fn new(small_ratio: f64) -> Self {
+        assert!((0.0..=1.0).contains(&small_ratio), "la part de la petite file doit être entre 0 et 1");
+        Self {
+            small: IndexList::new(),
+            main: IndexList::new(),
+            ghosts: GhostList::new(),
+            frequencies: Vec::new(),
+            hashes: Vec::new(),
+            small_ratio,
+            small_capacity: 1,
+            ghost_capacity: 0,
+            from_ghost: false,
+        }
+    }
+
+    /// Retire les fantômes les plus anciens tant que G dépasse la taille de M
+    fn trim_ghosts(&mut self) {
+        while self.ghosts.len() > self.ghost_capacity && self.ghosts.pop_oldest().is_some() {}
+    }
+
+    /// Choisit l'élément à retirer de S, en faisant passer dans M les éléments relus
+    fn evict_small(&mut self) -> Option<usize> {
+        while let Some(slot) = self.small.front() {
+            if self.frequencies[slot] == 0 {
+                self.ghosts.push(self.hashes[slot]);
+                self.trim_ghosts();
+                return Some(slot);
+            }
+            self.small.remove(slot);
+            self.frequencies[slot] = 0;
+            self.main.push_back(slot);
+        }
+        self.evict_main()
+    }
+
+    /// Choisit l'élément à retirer de M, en replaçant en fin de M les éléments relus
+    fn evict_main(&mut self) -> Option<usize> {
+        while let Some(slot) = self.main.front() {
+            if self.frequencies[slot] == 0 {
+                return Some(slot);
+            }
+            self.frequencies[slot] -= 1;
+            self.main.move_to_back(slot);
+        }
+        self.small.front()
+    }
+}
+
+impl Default for S3Fifo {
+    /// Petite file de 10 % du cache
+    fn default() -> Self {
+        Self::new(0.1)
+    }
+}
+
+impl EvictionPolicy for S3Fifo {
+    const PROMOTE_ON_ACCESS: bool = false;
+
+    fn on_capacity(&mut self, capacity: usize) {
+        self.small_capacity = ((capacity as f64 * self.small_ratio) as usize).max(1);
+        self.ghost_capacity = capacity.saturating_sub(self.small_capacity).max(1);
+        self.trim_ghosts();
+    }
+
+    fn before_insert(&mut self, hash: u64) {
+        self.from_ghost = self.ghosts.remove(hash);
+    }
+
+    fn on_insert(&mut self, slot: usize, hash: u64) {
+        if slot >= self.hashes.len() {
+            self.hashes.resize(slot + 1, 0);
+            self.frequencies.resize(slot + 1, 0);
+        }
+        self.hashes[slot] = hash;
+        self.frequencies[slot] = 0;
+        if std::mem::take(&mut self.from_ghost) {
+            self.main.push_back(slot);
+        } else {
+            self.small.push_back(slot);
+        }
+    }
+
+    fn on_access(&mut self, slot: usize) {
+        self.frequencies[slot] = (self.frequencies[slot] + 1).min(MAX_FREQUENCY);
+    }
+
+    fn on_remove(&mut self, slot: usize) {
+        if !self.small.remove(slot) {
+            self.main.remove(slot);
+        }
+    }
+
+    fn choose_victim(&mut self, _order: &IndexList) -> Option<usize> {
+        if self.small.len() >= self.small_capacity || self.main.is_empty() {
+            self.evict_small()
+        } else {
+            self.evict_main()
+        }
+    }
+}
@@ -0,0 +1,77 @@
+use crate::cache::policy::{EvictionPolicy, IndexList};
+
+/// Politique SIEVE
+///
+/// Les éléments sont rangés dans une file, du plus ancien au plus récent, qu'une aiguille
+/// parcourt du plus ancien vers le plus récent. Une lecture ne fait que marquer l'élément comme
+/// visité. Pour faire de la place, l'aiguille retire le premier élément non visité qu'elle
+/// rencontre et efface la marque des éléments visités qu'elle dépasse. Contrairement à CLOCK,
+/// les éléments dépassés restent à leur place : les nouveaux éléments, peu utilisés, sont
+/// retirés plus vite que les anciens éléments souvent lus
+///
+/// # Exemples
+///
+/// ```
+/// use hashmap_cache::cache::SieveCache;
+/// use hashmap_cache::cache::trait_cache::TraitCache;
+///
+/// let mut cache = SieveCache::with_default_policy(3);
+///
+/// cache.put("A", 1); // [A,B,C], aiguille sur "A"
+/// cache.put("B", 2);
+/// cache.put("C", 3);
+/// cache.get("A"); // "A" est marqué
+///
+/// cache.put("D", 4); // [A,C,D], "B" est retiré, l'aiguille passe sur "C"
+/// cache.put("E", 5); // [A,D,E], "C" est retiré
+/// assert!(cache.contains("A"));
+/// assert!(!cache.contains("C"));
+/// ```
+#[derive(Default)]
+pub struct Sieve {
+    queue: IndexList,
+    visited: Vec<bool>,
+    hand: Option<usize>,
+}
+
+impl Sieve {
+    /// Créé une politique SIEVE vide
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl EvictionPolicy for Sieve {
+    const PROMOTE_ON_ACCESS: bool = false;
+
+    fn on_insert(&mut self, slot: usize, _hash: u64) {
+        if slot >= self.visited.len() {
+            self.visited.resize(slot + 1, false);
+        }
+        self.visited[slot] = false;
+        self.queue.push_back(slot);
+    }
+
+    fn on_access(&mut self, slot: usize) {
+        self.visited[slot] = true;
+    }
+
+    fn on_remove(&mut self, slot: usize) {
+        if self.hand == Some(slot) {
+            self.hand = self.queue.next(slot);
+        }
+        self.queue.remove(slot);
+    }
+
+    fn choose_victim(&mut self, _order: &IndexList) -> Option<usize> {
+        let mut slot = self.hand.or_else(|| self.queue.front())?;
+        while self.visited[slot] {
+            self.visited[slot] = false;
+            // Arrivée au plus récent, l'aiguille repart du plus ancien
+            slot = self.queue.next(slot).or_else(|| self.queue.front())?;
+        }
+        // L'aiguille avancera sur l'élément suivant lors du retrait de la victime
+        self.hand = Some(slot);
+        Some(slot)
+    }
+}
@@ -0,0 +1,113 @@
+/// Graines qui rendent indépendantes les lignes du sketch
+const SEEDS: [u64; 4] = [
+    0xC3A5_C85C_97CB_3127,
+    0xB492_B66F_BE98_F273,
+    0x9AE1_6A3B_2F90_404F,
+    0xCBF2_9CE4_8422_2325,
+];
+
+/// Valeur maximale d'un compteur (compteurs sur 4 bits)
+const MAX_COUNT: u8 = 15;
+
+/// Nombre maximal de compteurs par ligne, pour borner la mémoire du sketch (4 Mio)
+const MAX_WIDTH: usize = 1 << 20;
+
+/// Sketch count-min qui estime la fréquence d'apparition des hashs de clés
+///
+/// Chaque hash incrémente un compteur par ligne et sa fréquence estimée est le plus petit
+/// de ces compteurs. Après un nombre d'incréments égal à dix fois la taille du cache,
+/// tous les compteurs sont divisés par deux pour que les clés populaires par le passé
+/// laissent la place aux clés populaires récemment
+pub(crate) struct CountMinSketch {
+    counters: Vec<u8>,
+    width_mask: usize,
+    additions: usize,
+    sample_size: usize,
+}
+
+impl CountMinSketch {
+    /// Créé un sketch dimensionné pour un cache de taille `capacity`
+    pub(crate) fn new(capacity: usize) -> Self {
+        let width = Self::width(capacity);
+        Self {
+            counters: vec![0; SEEDS.len() * width],
+            width_mask: width - 1,
+            additions: 0,
+            sample_size: Self::sample_size(capacity),
+        }
+    }
+
+    /// Redimensionne le sketch pour un cache de taille `capacity` en gardant les fréquences estimées
+    ///
+    /// Une colonne correspond aux bits de poids faible du hash mélangé : en s'élargissant, chaque
+    /// compteur est recopié dans les colonnes qui partagent ces bits, en se rétrécissant,
+    /// la colonne garde le plus grand des compteurs qui y tombent
+    pub(crate) fn resize(&mut self, capacity: usize) {
+        let width = Self::width(capacity);
+        let old_width = self.width_mask + 1;
+        if width != old_width {
+            let mut counters = vec![0; SEEDS.len() * width];
+            for row in 0..SEEDS.len() {
+                for column in 0..width.max(old_width) {
+                    let old = self.counters[row * old_width + (column & self.width_mask)];
+                    let counter = &mut counters[row * width + (column & (width - 1))];
+                    *counter = (*counter).max(old);
+                }
+            }
+            self.counters = counters;
+            self.width_mask = width - 1;
+        }
+        self.sample_size = Self::sample_size(capacity);
+    }
+
+    /// Nombre de compteurs par ligne pour un cache de taille `capacity`, une puissance de deux
+    fn width(capacity: usize) -> usize {
+        capacity.clamp(64, MAX_WIDTH).next_power_of_two()
+    }
+
+    /// Nombre d'incréments entre deux vieillissements pour un cache de taille `capacity`
+    fn sample_size(capacity: usize) -> usize {
+        capacity.max(1).saturating_mul(10)
+    }
+
+    /// Retourne l'indice du compteur du hash dans la ligne `row`
+    fn index(&self, hash: u64, row: usize) -> usize {
+        let mixed = (hash ^ SEEDS[row]).wrapping_mul(0x9E37_79B9_7F4A_7C15);
+        let column = (mixed >> 32) as usize & self.width_mask;
+        row * (self.width_mask + 1) + column
+    }
+
+    /// Retourne la fréquence estimée du hash
+    pub(crate) fn estimate(&self, hash: u64) -> u8 {
+        (0..SEEDS.len())
+            .map(|row| self.counters[self.index(hash, row)])
+            .min()
+            .unwrap_or(0)
+    }
+
+    /// Compte une apparition du hash
+    pub(crate) fn increment(&mut self, hash: u64) {
+        let mut incremented = false;
+        for row in 0..SEEDS.len() {
+            let index = self.index(hash, row);
+            if self.counters[index] < MAX_COUNT {
+                self.counters[index] += 1;
+                incremented = true;
+            }
+        }
+        if incremented {
+            self.additions += 1;
+            if self.additions >= self.sample_size {
+                self.age();
+            }
+        }
+    }
+
+    /// Divise par deux tous les compteurs
+    fn age(&mut self) {
+        for counter in &mut self.counters {
+            *counter /= 2;
+        }
+        self.additions /= 2;
+    }
+}
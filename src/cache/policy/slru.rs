@@ -0,0 +1,101 @@
+use crate::cache::policy::{EvictionPolicy, IndexList};
+
+/// Politique LRU segmentée (SLRU), résistante aux parcours
+///
+/// Les nouveaux éléments entrent dans le segment probatoire. Un élément relu pendant
+/// qu'il est probatoire passe dans le segment protégé, dont la taille est une fraction
+/// de la taille du cache. Lorsque le segment protégé déborde, son élément le moins récent
+/// redevient probatoire. L'élément retiré est le moins récent du segment probatoire :
+/// une série de clés vues une seule fois (un parcours complet d'une table par exemple)
+/// ne remplace que le segment probatoire et laisse intactes les clés populaires
+///
+/// # Exemples
+///
+/// ```
+/// use hashmap_cache::cache::SlruCache;
+/// use hashmap_cache::cache::policy::SegmentedLru;
+/// use hashmap_cache::cache::trait_cache::TraitCache;
+///
+/// let mut cache = SlruCache::with_policy(3, SegmentedLru::new(0.5));
+///
+/// cache.put("A", 1); // probatoire == [A]
+/// cache.get("A"); // protégé == [A]
+///
+/// for key in ["B", "C", "D", "E"] {
+///     cache.put(key, 0);
+/// } // probatoire == [D,E], protégé == [A]
+///
+/// assert!(cache.contains("A"));
+/// ```
+pub struct SegmentedLru {
+    probation: IndexList,
+    protected: IndexList,
+    protected_ratio: f64,
+    protected_capacity: usize,
+}
+
+impl SegmentedLru {
+    /// Créé une politique SLRU dont le segment protégé occupe `protected_ratio` du cache
+    ///
+    /// # Arguments
+    /// - `protected_ratio` : La part du cache réservée au segment protégé, entre 0 et 1
+    ///
+    /// # Panics
+    /// Si `protected_ratio` n'est pas entre 0 et 1
+    pub fn new(protected_ratio: f64) -> Self {
+        assert!((0.0..=1.0).contains(&protected_ratio), "la part protégée doit être entre 0 et 1");
+        Self {
+            probation: IndexList::new(),
+            protected: IndexList::new(),
+            protected_ratio,
+            protected_capacity: 0,
+        }
+    }
+
+    /// Replace dans le segment probatoire les éléments protégés qui dépassent sa taille
+    fn demote_overflow(&mut self) {
+        while self.protected.len() > self.protected_capacity {
+            if let Some(slot) = self.protected.front() {
+                self.protected.remove(slot);
+                self.probation.push_back(slot);
+            }
+        }
+    }
+}
+
+impl Default for SegmentedLru {
+    /// Segment protégé de 80 % du cache
+    fn default() -> Self {
+        Self::new(0.8)
+    }
+}
+
+impl EvictionPolicy for SegmentedLru {
+    fn on_capacity(&mut self, capacity: usize) {
+        self.protected_capacity = (capacity as f64 * self.protected_ratio) as usize;
+        self.demote_overflow();
+    }
+
+    fn on_insert(&mut self, slot: usize, _hash: u64) {
+        self.probation.push_back(slot);
+    }
+
+    fn on_access(&mut self, slot: usize) {
+        if self.probation.remove(slot) {
+            self.protected.push_back(slot);
+            self.demote_overflow();
+        } else {
+            self.protected.move_to_back(slot);
+        }
+    }
+
+    fn on_remove(&mut self, slot: usize) {
+        if !self.probation.remove(slot) {
+            self.protected.remove(slot);
+        }
+    }
+
+    fn choose_victim(&mut self, _order: &IndexList) -> Option<usize> {
+        self.probation.front().or_else(|| self.protected.front())
+    }
+}
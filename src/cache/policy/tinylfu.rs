@@ -0,0 +1,134 @@
+use crate::cache::policy::sketch::CountMinSketch;
+use crate::cache::policy::{EvictionPolicy, IndexList, SegmentedLru};
+
+/// Politique W-TinyLFU
+///
+/// Les nouveaux éléments entrent dans une petite fenêtre LRU (1 % du cache par défaut).
+/// Le reste du cache est une région principale segmentée (probatoire et protégée, voir
+/// [`SegmentedLru`](crate::cache::policy::SegmentedLru)). Lorsque la fenêtre déborde, son
+/// élément le moins récent devient candidat à la région principale : il n'y entre que si
+/// sa fréquence estimée par un sketch count-min est supérieure à celle de l'élément que
+/// la région principale retirerait à sa place. Le sketch vieillit régulièrement pour
+/// oublier les fréquences anciennes
+///
+/// # Exemples
+///
+/// ```
+/// use hashmap_cache::cache::WTinyLfuCache;
+/// use hashmap_cache::cache::trait_cache::TraitCache;
+///
+/// let mut cache = WTinyLfuCache::with_default_policy(10);
+///
+/// cache.extend((0..5).map(|key| (key, key * 10)));
+/// cache.put(99, 0); // fenêtre == [99], probatoire == [0, 1, 2, 3, 4]
+/// for key in 0..5 {
+///     cache.get(&key);
+/// } // protégé == [0, 1, 2, 3, 4]
+///
+/// for key in 100..200 {
+///     cache.put(key, 0);
+/// } // les clés vues une seule fois ne remplacent pas les clés populaires
+///
+/// assert!((0..5).all(|key| cache.contains(&key)));
+/// ```
+pub struct WTinyLfu {
+    window: IndexList,
+    main: SegmentedLru,
+    sketch: CountMinSketch,
+    hashes: Vec<u64>,
+    window_ratio: f64,
+    window_capacity: usize,
+}
+
+impl WTinyLfu {
+    /// Créé une politique W-TinyLFU dont la fenêtre occupe 1 % du cache
+    pub fn new() -> Self {
+        Self::with_window_ratio(0.01)
+    }
+
+    /// Créé une politique W-TinyLFU dont la fenêtre occupe `window_ratio` du cache
+    ///
+    /// La fenêtre contient toujours au moins un élément
+    ///
+    /// # Arguments
+    /// - `window_ratio` : La part du cache réservée à la fenêtre, entre 0 et 1
+    ///
+    /// # Panics
+    /// Si `window_ratio` n'est pas entre 0 et 1
+    pub fn with_window_ratio(window_ratio: f64) -> Self {
+        assert!((0.0..=1.0).contains(&window_ratio), "la part de la fenêtre doit être entre 0 et 1");
+        Self {
+            window: IndexList::new(),
+            main: SegmentedLru::default(),
+            sketch: CountMinSketch::new(0),
+            hashes: Vec::new(),
+            window_ratio,
+            window_capacity: 1,
+        }
+    }
+}
+
+impl Default for WTinyLfu {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl EvictionPolicy for WTinyLfu {
+    fn on_capacity(&mut self, capacity: usize) {
+        self.window_capacity = ((capacity as f64 * self.window_ratio) as usize).max(1);
+        self.main.on_capacity(capacity.saturating_sub(self.window_capacity));
+        self.sketch.resize(capacity);
+    }
+
+    fn on_insert(&mut self, slot: usize, hash: u64) {
+        if slot >= self.hashes.len() {
+            self.hashes.resize(slot + 1, 0);
+        }
+        self.hashes[slot] = hash;
+        self.sketch.increment(hash);
+        self.window.push_back(slot);
+        // Tant que le cache n'est pas plein, la fenêtre déborde sans concurrence
+        while self.window.len() > self.window_capacity {
+            if let Some(candidate) = self.window.front() {
+                self.window.remove(candidate);
+                self.main.on_insert(candidate, self.hashes[candidate]);
+            }
+        }
+    }
+
+    fn on_access(&mut self, slot: usize) {
+        self.sketch.increment(self.hashes[slot]);
+        if self.window.contains(slot) {
+            self.window.move_to_back(slot);
+        } else {
+            self.main.on_access(slot);
+        }
+    }
+
+    fn on_remove(&mut self, slot: usize) {
+        if !self.window.remove(slot) {
+            self.main.on_remove(slot);
+        }
+    }
+
+    fn choose_victim(&mut self, order: &IndexList) -> Option<usize> {
+        let main_victim = self.main.choose_victim(order);
+        if self.window.len() < self.window_capacity {
+            // La fenêtre ne déborde pas : la région principale fait de la place
+            return main_victim.or_else(|| self.window.front());
+        }
+        let candidate = self.window.front()?;
+        let Some(victim) = main_victim else {
+            return Some(candidate);
+        };
+        if self.sketch.estimate(self.hashes[candidate]) > self.sketch.estimate(self.hashes[victim]) {
+            // Le candidat est plus fréquent : il entre dans la région principale
+            self.window.remove(candidate);
+            self.main.on_insert(candidate, self.hashes[candidate]);
+            Some(victim)
+        } else {
+            Some(candidate)
+        }
+    }
+}
@@ -1,17 +1,66 @@
-use std::collections::{HashMap, VecDeque};
+use std::borrow::Borrow;
+use std::hash::Hash;
 
-pub struct Cache<K, V>
-{
-    size: usize,
-    cache_content: HashMap<K, V>,
-    cache_order: VecDeque<K>,
+/// Résultat d'un ajout dans le cache avec `put`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PutResult<K, V> {
+    /// La clé a été ajoutée sans retirer d'élément
+    Inserted,
+    /// La clé était déjà présente, l'ancienne valeur est retournée avec les couples clé-valeur
+    /// retirés si la nouvelle valeur, plus lourde, dépasse le poids maximal d'un cache pondéré
+    Replaced(V, Vec<(K, V)>),
+    /// La clé a été ajoutée et les couples clé-valeur choisis par la politique d'éviction
+    /// (les plus anciens pour un cache LRU) ont été retirés pour lui faire de la place,
+    /// dans l'ordre de leur retrait. Un cache borné par son nombre d'éléments n'en retire qu'un,
+    /// un cache pondéré peut en retirer plusieurs
+    Evicted(Vec<(K, V)>),
+    /// Le couple clé-valeur n'a pas pu être conservé par le cache (un cache de taille 0
+    /// ou un élément plus lourd que le poids maximal d'un cache pondéré)
+    Rejected(K, V),
 }
 
 pub trait TraitCache<K ,V>
 {
-    fn put(&mut self, key: K, value: V);
+    fn put(&mut self, key: K, value: V) -> PutResult<K, V>;
+
+    fn get<Q>(&mut self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized;
+
+    fn get_mut<Q>(&mut self, key: &Q) -> Option<&mut V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized;
+
+    fn peek<Q>(&self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized;
+
+    fn peek_mut<Q>(&mut self, key: &Q) -> Option<&mut V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized;
 
-    fn get(&mut self, key: K) -> Option<&V>;
+    fn contains<Q>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized;
 
-    fn move_key_end_cache(&mut self, key: &K);
-}
\ No newline at end of file
+    fn remove<Q>(&mut self, key: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized;
+
+    fn len(&self) -> usize;
+
+    fn is_empty(&self) -> bool;
+
+    fn capacity(&self) -> usize;
+
+    fn move_key_end_cache<Q>(&mut self, key: &Q)
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized;
+}
@@ -5,8 +5,34 @@ pub use cache::Cache;
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::cache::trait_cache::TraitCache;
+    use crate::cache::{
+        ArcCache, ClockCache, Entry, FifoCache, GdsfCache, HeapSize, LfuCache, LirsCache, MockClock, MruCache, RandomCache,
+        RemovalCause, S3FifoCache, SieveCache, SlruCache, WTinyLfuCache,
+    };
+    use crate::cache::policy::{
+        AdaptiveReplacement, Clock, EvictionPolicy, Fifo, Gdsf, IndexList, Lirs, Mru, RandomEviction, S3Fifo, SegmentedLru,
+        Sieve, WTinyLfu,
+    };
+    use crate::cache::trait_cache::{PutResult, TraitCache};
+    use std::cell::Cell;
+    use std::collections::HashMap;
+    use std::hash::{Hash, Hasher};
+    use std::sync::{Arc, Mutex};
+    use std::time::Duration;
 
+    /// Clé dont toutes les instances ont le même hash
+    #[derive(PartialEq, Eq, Debug)]
+    struct SameHash(u32);
+
+    impl Hash for SameHash {
+        fn hash<H: Hasher>(&self, state: &mut H) {
+            0.hash(state);
+        }
+    }
+
+    /// Valeur qui n'implémente pas `Clone`
+    #[derive(PartialEq, Debug)]
+    struct NotClone(u32);
 
     #[test]
     fn test_lru_cache() {
@@ -50,4 +76,1046 @@ mod tests {
         // Cache == [C, A, X]
         assert_eq!(my_value, None);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_lru_cache_update_order() {
+        let mut cache = Cache::new(3);
+        for i in 0..1000 {
+            cache.put(i, i * 10);
+        }
+        // Cache == [997, 998, 999]
+
+        cache.put(997, 0);
+        // Mise à jour : Cache == [998, 999, 997]
+
+        cache.put(1000, 10000);
+        // Cache == [999, 997, 1000]
+
+        assert_eq!(cache.get(&998), None);
+        assert_eq!(cache.get(&997), Some(&0));
+        // Cache == [999, 1000, 997]
+
+        cache.put(1001, 10010);
+        // Cache == [1000, 997, 1001]
+
+        assert_eq!(cache.get(&999), None);
+        assert_eq!(cache.get(&1000), Some(&10000));
+        assert_eq!(cache.get(&1001), Some(&10010));
+    }
+
+    #[test]
+    fn test_lru_cache_remove_and_peek() {
+        let mut cache = Cache::new(3);
+        cache.put("A", String::from("value_a"));
+        cache.put("B", String::from("value_b"));
+        cache.put("C", String::from("value_c"));
+        // Cache == [A, B, C]
+
+        assert_eq!(cache.peek(&"A"), Some(&String::from("value_a")));
+        assert!(cache.contains(&"B"));
+        // Cache == [A, B, C] (peek et contains ne modifient pas l'ordre)
+
+        assert_eq!(cache.remove(&"B"), Some(String::from("value_b")));
+        assert!(!cache.contains(&"B"));
+        assert_eq!(cache.len(), 2);
+        // Cache == [A, C]
+
+        cache.put("D", String::from("value_d"));
+        // Cache == [A, C, D]
+        assert_eq!(cache.len(), 3);
+        assert_eq!(cache.capacity(), 3);
+
+        cache.put("E", String::from("value_e"));
+        // Cache == [C, D, E]
+        assert_eq!(cache.peek(&"A"), None);
+        assert_eq!(cache.len(), 3);
+
+        assert_eq!(cache.remove(&"C"), Some(String::from("value_c")));
+        assert_eq!(cache.remove(&"D"), Some(String::from("value_d")));
+        assert_eq!(cache.remove(&"E"), Some(String::from("value_e")));
+        assert!(cache.is_empty());
+    }
+
+    #[test]
+    fn test_lru_cache_borrowed_keys() {
+        let mut cache: Cache<String, u32> = Cache::new(2);
+        cache.put(String::from("A"), 1);
+        cache.put(String::from("B"), 2);
+        // Cache == [A, B]
+
+        // Les recherches se font avec des &str, sans allouer de String
+        assert_eq!(cache.get("A"), Some(&1));
+        // Cache == [B, A]
+        assert_eq!(cache.peek("B"), Some(&2));
+        assert!(cache.contains("A"));
+        assert!(!cache.contains("X"));
+
+        cache.put(String::from("C"), 3);
+        // Cache == [A, C]
+        assert!(!cache.contains("B"));
+
+        cache.move_key_end_cache("A");
+        // Cache == [C, A]
+        assert_eq!(cache.remove("C"), Some(3));
+        assert_eq!(cache.len(), 1);
+    }
+
+    #[test]
+    fn test_lru_cache_entry() {
+        let mut cache = Cache::new(2);
+        cache.put("A", 1);
+        cache.put("B", 2);
+        // Cache == [A, B]
+
+        assert_eq!(cache.entry("A").and_modify(|v| *v += 10).or_insert(0), Ok(&mut 11));
+        // Cache == [B, A] (l'entrée présente est placée à la fin)
+
+        assert_eq!(cache.entry("C").and_modify(|v| *v += 10).or_insert(3), Ok(&mut 3));
+        // Cache == [A, C] ("B" est supprimé)
+
+        assert_eq!(cache.get("B"), None);
+        assert_eq!(cache.peek("A"), Some(&11));
+        assert_eq!(cache.peek("C"), Some(&3));
+
+        let result: Result<Result<&i32, i32>, &str> = cache.get_or_try_insert_with("D", || Err("erreur"));
+        assert_eq!(result, Err("erreur"));
+        // Cache == [A, C] (rien n'est inséré ni supprimé)
+        assert_eq!(cache.len(), 2);
+
+        assert_eq!(cache.get_or_insert_with("A", || 0), Ok(&11));
+        // Cache == [C, A]
+        assert_eq!(cache.get_or_insert_with("D", || 4), Ok(&4));
+        // Cache == [A, D]
+        assert!(!cache.contains("C"));
+
+        match cache.entry("D") {
+            Entry::Occupied(entry) => assert_eq!(entry.remove(), 4),
+            Entry::Vacant(_) => unreachable!(),
+        }
+        // Cache == [A]
+        assert_eq!(cache.len(), 1);
+    }
+
+    #[test]
+    fn test_lru_cache_without_clone() {
+        let mut cache = Cache::new(3);
+        cache.put(SameHash(1), NotClone(10));
+        cache.put(SameHash(2), NotClone(20));
+        cache.put(SameHash(3), NotClone(30));
+        // Cache == [1, 2, 3] (toutes les clés ont le même hash)
+
+        assert_eq!(cache.get(&SameHash(1)), Some(&NotClone(10)));
+        // Cache == [2, 3, 1]
+
+        assert_eq!(cache.remove(&SameHash(3)), Some(NotClone(30)));
+        // Cache == [2, 1]
+
+        cache.put(SameHash(4), NotClone(40));
+        cache.put(SameHash(5), NotClone(50));
+        // Cache == [1, 4, 5]
+
+        assert!(!cache.contains(&SameHash(2)));
+        assert!(!cache.contains(&SameHash(3)));
+        assert_eq!(cache.peek(&SameHash(1)), Some(&NotClone(10)));
+        assert_eq!(cache.peek(&SameHash(4)), Some(&NotClone(40)));
+        assert_eq!(cache.peek(&SameHash(5)), Some(&NotClone(50)));
+
+        cache.put(SameHash(4), NotClone(41));
+        // Cache == [1, 5, 4]
+        assert_eq!(cache.remove(&SameHash(1)), Some(NotClone(10)));
+        assert_eq!(cache.remove(&SameHash(5)), Some(NotClone(50)));
+        assert_eq!(cache.remove(&SameHash(4)), Some(NotClone(41)));
+        assert!(cache.is_empty());
+    }
+
+    #[test]
+    fn test_lru_cache_mutable_access() {
+        let mut cache = Cache::new(3);
+        cache.put("A", vec![1]);
+        cache.put("B", vec![2]);
+        cache.put("C", vec![3]);
+        // Cache == [A, B, C]
+
+        cache.get_mut("A").unwrap().push(10);
+        // Cache == [B, C, A]
+
+        cache.peek_mut("B").unwrap().push(20);
+        // Cache == [B, C, A] (peek_mut ne modifie pas l'ordre)
+
+        assert_eq!(cache.get_mut("X"), None);
+        assert_eq!(cache.peek_mut("X"), None);
+
+        cache.put("D", vec![4]);
+        // Cache == [C, A, D]
+        assert_eq!(cache.peek("B"), None);
+        assert_eq!(cache.peek("A"), Some(&vec![1, 10]));
+    }
+
+    #[test]
+    fn test_lru_cache_iterators() {
+        let mut cache = Cache::new(3);
+        cache.put("A", 1);
+        cache.put("B", 2);
+        cache.put("C", 3);
+        cache.get("A");
+        // Cache == [B, C, A]
+
+        assert_eq!(cache.keys().collect::<Vec<_>>(), [&"A", &"C", &"B"]);
+        assert_eq!(cache.values().rev().collect::<Vec<_>>(), [&2, &3, &1]);
+        assert_eq!(cache.iter().len(), 3);
+
+        for (_, value) in &mut cache {
+            *value += 10;
+        }
+        // Cache == [B, C, A] (les itérateurs ne modifient pas l'ordre)
+
+        let mut iter = cache.iter_mut();
+        *iter.next().unwrap().1 += 100;
+        *iter.next_back().unwrap().1 += 100;
+        assert_eq!(iter.len(), 1);
+        assert_eq!(cache.peek("A"), Some(&111));
+        assert_eq!(cache.peek("B"), Some(&112));
+
+        cache.put("D", 4);
+        // Cache == [C, A, D]
+        assert!(!cache.contains("B"));
+
+        let mut drain = cache.drain();
+        assert_eq!(drain.next(), Some(("D", 4)));
+        assert_eq!(drain.next_back(), Some(("C", 13)));
+        drop(drain);
+        // Cache == [] (le reste est retiré à la destruction de l'itérateur)
+        assert!(cache.is_empty());
+
+        cache.extend([("E", 5), ("F", 6), ("G", 7), ("H", 8)]);
+        // Cache == [F, G, H]
+        let pairs: Vec<_> = cache.into_iter().collect();
+        assert_eq!(pairs, [("H", 8), ("G", 7), ("F", 6)]);
+    }
+
+    #[test]
+    fn test_lru_cache_iter_mut_both_ends() {
+        let mut cache = Cache::new(5);
+        cache.extend((0..7).map(|key| (key, key.to_string())));
+        cache.remove(&4);
+        cache.put(7, 7.to_string());
+        cache.get(&3);
+        // Cache == [2, 5, 6, 7, 3], l'emplacement de 4 a été réutilisé par 7
+
+        // Les références rendues par les deux bouts coexistent jusqu'à ce qu'ils se rejoignent
+        let mut iter = cache.iter_mut();
+        let mut values = Vec::new();
+        while let Some((_, front)) = iter.next() {
+            values.push(front);
+            if let Some((_, back)) = iter.next_back() {
+                values.push(back);
+            }
+        }
+        assert_eq!(iter.next_back(), None);
+        for value in &mut values {
+            value.push('!');
+        }
+        assert_eq!(values, ["3!", "2!", "7!", "5!", "6!"]);
+
+        assert_eq!(
+            cache.iter().map(|(_, value)| value.as_str()).collect::<Vec<_>>(),
+            ["3!", "7!", "6!", "5!", "2!"]
+        );
+    }
+
+    #[test]
+    fn test_lru_cache_set_capacity() {
+        let mut cache = Cache::new(4);
+        cache.extend([("A", 1), ("B", 2), ("C", 3), ("D", 4)]);
+        cache.get("A");
+        // Cache == [B, C, D, A]
+
+        assert_eq!(cache.set_capacity(2), [("B", 2), ("C", 3)]);
+        // Cache == [D, A]
+        assert_eq!(cache.keys().collect::<Vec<_>>(), [&"A", &"D"]);
+
+        cache.put("E", 5);
+        // Cache == [A, E]
+        assert!(!cache.contains("D"));
+
+        assert!(cache.set_capacity(3).is_empty());
+        cache.put("F", 6);
+        // Cache == [A, E, F]
+        assert_eq!(cache.len(), 3);
+
+        assert_eq!(cache.set_capacity(0), [("A", 1), ("E", 5), ("F", 6)]);
+        // Cache == []
+        cache.put("G", 7);
+        assert!(cache.is_empty());
+        assert_eq!(cache.get("G"), None);
+
+        // L'API entry rend la valeur au lieu de l'insérer
+        assert_eq!(cache.entry("G").or_insert(7), Err(7));
+        assert_eq!(cache.get_or_insert_with("G", || 7), Err(7));
+        assert_eq!(cache.get_or_try_insert_with("G", || Ok::<_, ()>(7)), Ok(Err(7)));
+        assert!(cache.is_empty());
+
+        // Les éléments expirés sont retournés en premier
+        let mut cache = Cache::new(3);
+        cache.extend([("A", 1), ("B", 2)]);
+        cache.put_with_ttl("C", 3, Duration::ZERO);
+        assert_eq!(cache.set_capacity(1), [("C", 3), ("A", 1)]);
+        assert_eq!(cache.keys().collect::<Vec<_>>(), [&"B"]);
+    }
+
+    #[test]
+    fn test_lru_cache_put_result() {
+        let mut cache = Cache::new(2);
+        assert_eq!(cache.put("A", 1), PutResult::Inserted);
+        assert_eq!(cache.put("B", 2), PutResult::Inserted);
+        // Cache == [A, B]
+
+        assert_eq!(cache.put("A", 10), PutResult::Replaced(1, vec![]));
+        // Cache == [B, A]
+
+        assert_eq!(cache.put("C", 3), PutResult::Evicted(vec![("B", 2)]));
+        // Cache == [A, C]
+
+        cache.set_capacity(0);
+        assert_eq!(cache.put("D", 4), PutResult::Rejected("D", 4));
+        assert!(cache.is_empty());
+    }
+
+    #[test]
+    fn test_lru_cache_removal_listener() {
+        let removed = Arc::new(Mutex::new(Vec::new()));
+        let mut cache = Cache::new(3);
+        let log = Arc::clone(&removed);
+        cache.set_removal_listener(move |key: &&str, value: &i32, cause| {
+            log.lock().unwrap().push((*key, *value, cause));
+        });
+
+        cache.extend([("A", 1), ("B", 2), ("C", 3), ("D", 4)]);
+        // Cache == [B, C, D]
+        cache.put("B", 20);
+        // Cache == [C, D, B]
+        if let Entry::Occupied(mut entry) = cache.entry("C") {
+            assert_eq!(entry.insert(30), Ok(3));
+        }
+        // Cache == [D, B, C]
+        cache.remove("D");
+        // Cache == [B, C]
+        cache.put("E", 5);
+        // Cache == [B, C, E]
+        cache.set_capacity(2);
+        // Cache == [C, E]
+        assert_eq!(cache.drain().count(), 2);
+        // Cache == [] (drain ne prévient pas le listener)
+        cache.put("F", 6);
+        cache.clear();
+        // Cache == []
+
+        assert_eq!(*removed.lock().unwrap(), [
+            ("A", 1, RemovalCause::Capacity),
+            ("B", 2, RemovalCause::Replaced),
+            ("C", 3, RemovalCause::Replaced),
+            ("D", 4, RemovalCause::Explicit),
+            ("B", 20, RemovalCause::Capacity),
+            ("F", 6, RemovalCause::Cleared),
+        ]);
+    }
+
+    /// Politique de test qui retire l'élément le plus récent et compte les appels reçus
+    #[derive(Default)]
+    struct MostRecent {
+        inserts: usize,
+        accesses: usize,
+        removes: usize,
+    }
+
+    impl EvictionPolicy for MostRecent {
+        fn on_insert(&mut self, _slot: usize, _hash: u64) {
+            self.inserts += 1;
+        }
+
+        fn on_access(&mut self, _slot: usize) {
+            self.accesses += 1;
+        }
+
+        fn on_remove(&mut self, _slot: usize) {
+            self.removes += 1;
+        }
+
+        fn choose_victim(&mut self, order: &IndexList) -> Option<usize> {
+            order.back()
+        }
+    }
+
+    /// Politique de test qui ne choisit jamais d'élément à retirer
+    struct NoVictim;
+
+    impl EvictionPolicy for NoVictim {
+        fn on_insert(&mut self, _slot: usize, _hash: u64) {}
+
+        fn on_access(&mut self, _slot: usize) {}
+
+        fn on_remove(&mut self, _slot: usize) {}
+
+        fn choose_victim(&mut self, _order: &IndexList) -> Option<usize> {
+            None
+        }
+    }
+
+    #[test]
+    fn test_cache_custom_policy() {
+        let mut cache = Cache::with_policy(2, MostRecent::default());
+        cache.put("A", 1);
+        cache.put("B", 2);
+        // Cache == [A, B]
+
+        cache.get("A");
+        // Cache == [B, A]
+
+        assert_eq!(cache.put("C", 3), PutResult::Evicted(vec![("A", 1)]));
+        // Cache == [B, C] (la politique retire l'élément le plus récent)
+
+        cache.remove("B");
+        // Cache == [C]
+
+        assert!(cache.contains("C"));
+        assert_eq!(cache.len(), 1);
+        assert_eq!(cache.policy().inserts, 3);
+        assert_eq!(cache.policy().accesses, 1);
+        assert_eq!(cache.policy().removes, 2);
+
+        // Une politique qui ne choisit aucun élément ne bloque pas la réduction du cache
+        let mut cache = Cache::with_policy(2, NoVictim);
+        cache.extend([("A", 1), ("B", 2)]);
+        assert!(cache.set_capacity(1).is_empty());
+        assert_eq!(cache.len(), 2);
+    }
+
+    #[test]
+    fn test_lfu_cache() {
+        let mut cache = LfuCache::with_default_policy(3);
+        cache.put("A", 1);
+        cache.put("B", 2);
+        cache.put("C", 3);
+        // Fréquences == A:1, B:1, C:1
+
+        cache.get("A");
+        cache.get("A");
+        cache.get("B");
+        // Fréquences == A:3, B:2, C:1
+
+        assert_eq!(cache.put("D", 4), PutResult::Evicted(vec![("C", 3)]));
+        // Fréquences == A:3, B:2, D:1
+
+        cache.get("D");
+        // Fréquences == A:3, B:2, D:2 (à fréquence égale, "B" est le moins récent)
+        assert_eq!(cache.put("E", 5), PutResult::Evicted(vec![("B", 2)]));
+        // Fréquences == A:3, D:2, E:1
+
+        // Une rafale de nouvelles clés ne retire pas les clés populaires
+        for (key, value) in [("F", 6), ("G", 7), ("H", 8)] {
+            cache.put(key, value);
+        }
+        // Fréquences == A:3, D:2, H:1
+        assert!(cache.contains("A"));
+        assert!(cache.contains("D"));
+        assert!(cache.contains("H"));
+
+        cache.remove("A");
+        cache.put("I", 9);
+        // Fréquences == D:2, H:1, I:1
+        assert_eq!(cache.put("J", 10), PutResult::Evicted(vec![("H", 8)]));
+        assert_eq!(cache.len(), 3);
+    }
+
+    #[test]
+    fn test_arc_cache() {
+        let mut cache = ArcCache::with_policy(2, AdaptiveReplacement::new());
+        cache.put("A", 1);
+        cache.put("B", 2);
+        // T1 == [A, B], T2 == [], p == 0
+
+        cache.get("A");
+        // T1 == [B], T2 == [A]
+
+        assert_eq!(cache.put("C", 3), PutResult::Evicted(vec![("B", 2)]));
+        // T1 == [C], T2 == [A], B1 == [B] (|T1| > p : le plus ancien de T1 est retiré)
+
+        assert_eq!(cache.put("B", 2), PutResult::Evicted(vec![("A", 1)]));
+        // "B" est dans B1 : p == 1, |T1| == p donc le plus ancien de T2 est retiré
+        // T1 == [C], T2 == [B], B1 == [], B2 == [A]
+        assert_eq!(cache.policy().target(), 1);
+
+        assert_eq!(cache.put("A", 1), PutResult::Evicted(vec![("C", 3)]));
+        // "A" est dans B2 : p == 0, |T1| > p donc le plus ancien de T1 est retiré
+        // T1 == [], T2 == [B, A], B1 == [C], B2 == []
+        assert_eq!(cache.policy().target(), 0);
+
+        assert_eq!(cache.put("D", 4), PutResult::Evicted(vec![("B", 2)]));
+        // T1 vide : le plus ancien de T2 est retiré
+        // T1 == [D], T2 == [A], B1 == [C], B2 == [B]
+        assert!(cache.contains("A"));
+        assert!(cache.contains("D"));
+    }
+
+    #[test]
+    fn test_arc_cache_scan() {
+        let mut cache = ArcCache::with_policy(4, AdaptiveReplacement::new());
+        for key in [1, 2] {
+            cache.put(key, key);
+            cache.get(&key);
+        }
+        // T2 == [1, 2]
+
+        // Un parcours de clés vues une seule fois ne remplace que T1
+        for key in 100..200 {
+            cache.put(key, key);
+        }
+        assert!(cache.contains(&1));
+        assert!(cache.contains(&2));
+        assert_eq!(cache.len(), 4);
+    }
+
+    #[test]
+    fn test_arc_cache_unbounded() {
+        // Deux fois la taille du cache ne doit pas dépasser usize::MAX
+        let mut cache = ArcCache::with_default_policy(usize::MAX);
+        cache.extend([("A", 1), ("B", 2), ("C", 3)]);
+        cache.get("A");
+        // T1 == [B, C], T2 == [A]
+
+        assert_eq!(cache.set_capacity(2), [("B", 2)]);
+        // T1 == [C], T2 == [A], B1 == [B]
+        assert!(cache.set_capacity(usize::MAX).is_empty());
+        assert_eq!(cache.put("B", 2), PutResult::Inserted);
+        // "B" est dans B1 : la place réservée à T1 augmente
+        assert_eq!(cache.policy().target(), 1);
+        assert_eq!(cache.len(), 3);
+    }
+
+    #[test]
+    fn test_slru_cache() {
+        let mut cache = SlruCache::with_policy(4, SegmentedLru::new(0.5));
+        cache.extend([("A", 1), ("B", 2), ("C", 3)]);
+        cache.get("A");
+        cache.get("B");
+        // Probatoire == [C], protégé == [A, B]
+
+        cache.get("C");
+        // Le segment protégé déborde : "A" redevient probatoire
+        // Probatoire == [A], protégé == [B, C]
+
+        cache.put("D", 4);
+        assert_eq!(cache.put("E", 5), PutResult::Evicted(vec![("A", 1)]));
+        // Probatoire == [D, E], protégé == [B, C]
+
+        // Un parcours complet ne remplace que le segment probatoire
+        for key in ["F", "G", "H", "I", "J"] {
+            cache.put(key, 0);
+        }
+        // Probatoire == [I, J], protégé == [B, C]
+        assert!(cache.contains("B"));
+        assert!(cache.contains("C"));
+        assert!(cache.contains("J"));
+        assert!(!cache.contains("E"));
+
+        // Réduire le cache réduit aussi le segment protégé
+        assert_eq!(cache.set_capacity(2), [("I", 0), ("J", 0)]);
+        cache.put("K", 11);
+        assert_eq!(cache.put("L", 12), PutResult::Evicted(vec![("K", 11)]));
+        assert!(cache.contains("C"));
+    }
+
+    #[test]
+    fn test_w_tiny_lfu_cache() {
+        let mut cache = WTinyLfuCache::with_policy(4, WTinyLfu::with_window_ratio(0.25));
+        cache.extend([("A", 1), ("B", 2), ("C", 3), ("D", 4)]);
+        // Fenêtre == [D], probatoire == [A, B, C]
+        cache.get("A");
+        cache.get("A");
+        cache.get("B");
+        // Fenêtre == [D], probatoire == [C], protégé == [A, B]
+
+        // "D" n'est pas plus fréquent que "C" : il n'entre pas dans la région principale
+        assert_eq!(cache.put("E", 5), PutResult::Evicted(vec![("D", 4)]));
+        cache.get("E");
+        cache.get("E");
+
+        // "E" est plus fréquent que "C" : il prend sa place
+        assert_eq!(cache.put("F", 6), PutResult::Evicted(vec![("C", 3)]));
+        // Fenêtre == [F], probatoire == [E], protégé == [A, B]
+
+        // Les clés vues une seule fois ne traversent que la fenêtre
+        for key in ["G", "H", "I", "J"] {
+            cache.put(key, 0);
+        }
+        assert!(["A", "B", "E", "J"].iter().all(|key| cache.contains(key)));
+        assert_eq!(cache.len(), 4);
+
+        // Les fréquences survivent aux changements de taille, même extrêmes
+        for _ in 0..3 {
+            cache.get("J");
+        }
+        cache.set_capacity(usize::MAX);
+        cache.set_capacity(4);
+        // "J" (4 utilisations) est plus fréquent que "E" (3 utilisations)
+        assert_eq!(cache.put("K", 0), PutResult::Evicted(vec![("E", 5)]));
+    }
+
+    #[test]
+    fn test_clock_and_sieve_cache() {
+        let mut clock = ClockCache::with_policy(3, Clock::new());
+        let mut sieve = SieveCache::with_policy(3, Sieve::new());
+        for (key, value) in [("A", 1), ("B", 2), ("C", 3)] {
+            clock.put(key, value);
+            sieve.put(key, value);
+        }
+        clock.get("A");
+        sieve.get("A");
+
+        // Une lecture ne modifie pas l'ordre du cache
+        assert_eq!(clock.keys().copied().collect::<Vec<_>>(), ["C", "B", "A"]);
+        assert_eq!(sieve.keys().copied().collect::<Vec<_>>(), ["C", "B", "A"]);
+
+        // "A" est marqué : l'aiguille le dépasse et retire "B"
+        assert_eq!(clock.put("D", 4), PutResult::Evicted(vec![("B", 2)]));
+        assert_eq!(sieve.put("D", 4), PutResult::Evicted(vec![("B", 2)]));
+        // CLOCK == [C, A, D], SIEVE == [A, C, D] avec l'aiguille sur "C"
+
+        clock.get("C");
+        sieve.get("C");
+        // CLOCK dépasse "C" puis retire "A" dont la marque a été effacée
+        assert_eq!(clock.put("E", 5), PutResult::Evicted(vec![("A", 1)]));
+        // SIEVE dépasse "C" puis retire "D" qui n'a jamais été lu
+        assert_eq!(sieve.put("E", 5), PutResult::Evicted(vec![("D", 4)]));
+
+        // L'aiguille repart du plus ancien : "A" et "C" ont perdu leur marque
+        assert_eq!(sieve.put("F", 6), PutResult::Evicted(vec![("A", 1)]));
+        assert_eq!(sieve.remove("C"), Some(3));
+        assert_eq!(sieve.put("G", 7), PutResult::Inserted);
+        assert_eq!(sieve.put("H", 8), PutResult::Evicted(vec![("E", 5)]));
+    }
+
+    #[test]
+    fn test_s3fifo_cache() {
+        let mut cache = S3FifoCache::with_policy(4, S3Fifo::new(0.5));
+        cache.extend([("A", 1), ("B", 2), ("C", 3), ("D", 4)]);
+        cache.get("A");
+        cache.get("B");
+        // S == [A, B, C, D], M == []
+
+        // "A" et "B" ont été relus et passent dans M, "C" est retiré
+        assert_eq!(cache.put("E", 5), PutResult::Evicted(vec![("C", 3)]));
+        // S == [D, E], M == [A, B], G == [C]
+
+        // "C" revient après son retrait : il entre directement dans M
+        cache.get("A");
+        assert_eq!(cache.put("C", 3), PutResult::Evicted(vec![("D", 4)]));
+        // S == [E], M == [A, B, C], G == [D]
+
+        // S est sous sa taille : M fait de la place, "A" relu est replacé en fin de M
+        assert_eq!(cache.put("F", 6), PutResult::Evicted(vec![("B", 2)]));
+        // S == [E, F], M == [C, A]
+        assert!(cache.contains("A"));
+        assert!(cache.contains("C"));
+
+        // Le plus ancien élément de S est retiré sans avoir été relu
+        assert_eq!(cache.put("G", 7), PutResult::Evicted(vec![("E", 5)]));
+    }
+
+    #[test]
+    fn test_lirs_cache() {
+        let mut cache = LirsCache::with_policy(3, Lirs::new(0.34));
+        cache.extend([("A", 1), ("B", 2), ("C", 3)]);
+        // S == [A, B, C], LIR == {A, B}, Q == [C]
+
+        // La clé HIR résidente est retirée mais reste dans S sans valeur
+        assert_eq!(cache.put("D", 4), PutResult::Evicted(vec![("C", 3)]));
+        // S == [A, B, C', D], Q == [D]
+
+        // "C" revient pendant qu'il est encore dans S : il devient LIR, "A" redevient HIR
+        assert_eq!(cache.put("C", 3), PutResult::Evicted(vec![("D", 4)]));
+        // S == [B, D', C], LIR == {B, C}, Q == [A]
+        assert_eq!(cache.put("E", 5), PutResult::Evicted(vec![("A", 1)]));
+
+        // Un parcours de clés vues une seule fois ne remplace que la place HIR
+        for key in ["F", "G", "H", "I", "J", "K"] {
+            cache.put(key, 0);
+        }
+        assert!(cache.contains("B"));
+        assert!(cache.contains("C"));
+        assert!(cache.contains("K"));
+        assert!(!cache.contains("J"));
+
+        // Une clé LIR retirée à la main laisse sa place à la prochaine clé insérée
+        assert_eq!(cache.remove("B"), Some(2));
+        assert_eq!(cache.put("L", 12), PutResult::Inserted);
+        assert_eq!(cache.put("M", 13), PutResult::Evicted(vec![("K", 0)]));
+        assert!(cache.contains("L"));
+
+        // Sans place LIR, le fond de S peut être une clé non résidente
+        let mut cache = LirsCache::with_policy(2, Lirs::new(1.0));
+        cache.extend([("A", 1), ("B", 2)]);
+        assert_eq!(cache.put("C", 3), PutResult::Evicted(vec![("A", 1)]));
+        // S == [A', B, C], Q == [B, C]
+        assert_eq!(cache.get("B"), Some(&2));
+        assert_eq!(cache.put("D", 4), PutResult::Evicted(vec![("C", 3)]));
+    }
+
+    #[test]
+    fn test_fifo_mru_random_cache() {
+        let mut fifo = FifoCache::with_policy(2, Fifo);
+        fifo.put("A", 1);
+        fifo.put("B", 2);
+        fifo.get("A");
+        // Une lecture ne protège pas "A" : FIFO == [A, B]
+        assert_eq!(fifo.put("C", 3), PutResult::Evicted(vec![("A", 1)]));
+        // Une mise à jour non plus : FIFO == [B, C]
+        assert_eq!(fifo.put("B", 20), PutResult::Replaced(2, vec![]));
+        assert_eq!(fifo.put("D", 4), PutResult::Evicted(vec![("B", 20)]));
+
+        let mut mru = MruCache::with_policy(2, Mru);
+        mru.put("A", 1);
+        mru.put("B", 2);
+        mru.get("A");
+        // MRU == [B, A]
+        assert_eq!(mru.put("C", 3), PutResult::Evicted(vec![("A", 1)]));
+        assert_eq!(mru.put("D", 4), PutResult::Evicted(vec![("C", 3)]));
+        assert!(mru.contains("B"));
+
+        // La même graine donne les mêmes retraits
+        let mut first = RandomCache::with_policy(4, RandomEviction::new(7));
+        let mut second = RandomCache::with_policy(4, RandomEviction::new(7));
+        for key in 0..100 {
+            assert_eq!(first.put(key, key), second.put(key, key));
+            if key % 3 == 0 {
+                assert_eq!(first.remove(&(key / 2)), second.remove(&(key / 2)));
+            }
+        }
+        assert!(first.len() <= 4);
+        assert_eq!(first.keys().collect::<Vec<_>>(), second.keys().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_gdsf_cache() {
+        let mut cache = GdsfCache::with_policy(3, Gdsf::new());
+        cache.put_with_cost("petit_cher", 1, 10, 100.0); // priorité 10
+        cache.put_with_cost("gros_cher", 2, 1000, 100.0); // priorité 0,1
+        cache.put_with_cost("petit", 3, 10, 10.0); // priorité 1
+
+        // Le gros élément est retiré avant le petit élément moins coûteux
+        assert_eq!(cache.put_with_cost("D", 4, 10, 10.0), PutResult::Evicted(vec![("gros_cher", 2)]));
+        assert_eq!(cache.policy().inflation(), 0.1);
+
+        // Un accès recalcule la priorité de "petit" : 0,1 + 2 * 10 / 10
+        cache.get("petit");
+        // "D" a la plus faible priorité : 0,1 + 1 * 10 / 10
+        assert_eq!(cache.put_with_cost("E", 5, 10, 10.0), PutResult::Evicted(vec![("D", 4)]));
+        assert_eq!(cache.policy().inflation(), 1.1);
+
+        // Mettre à jour un élément remplace sa taille et son coût
+        assert_eq!(cache.put_with_cost("petit_cher", 10, 1000, 1.0), PutResult::Replaced(1, vec![]));
+        assert_eq!(cache.put("F", 6), PutResult::Evicted(vec![("petit_cher", 10)]));
+        assert!(cache.contains("petit"));
+        assert!(cache.contains("E"));
+    }
+
+    #[test]
+    fn test_weighted_cache() {
+        let removed = Arc::new(Mutex::new(Vec::new()));
+        let mut cache = Cache::with_weigher(10, |_key: &&str, value: &String| value.len() as u64);
+        let log = Arc::clone(&removed);
+        cache.set_removal_listener(move |key, _value, cause| log.lock().unwrap().push((*key, cause)));
+
+        cache.put("A", String::from("aaa"));
+        cache.put("B", String::from("bbb"));
+        cache.put("C", String::from("ccc"));
+        cache.get("A");
+        // Cache == [B, C, A], poids 9
+        assert_eq!(cache.weight(), 9);
+        assert_eq!(cache.max_weight(), Some(10));
+        assert_eq!(cache.capacity(), usize::MAX);
+
+        // Le nouvel élément ne tient qu'en retirant "B" puis "C", tous deux sont retournés
+        assert_eq!(
+            cache.put("D", String::from("dddddd")),
+            PutResult::Evicted(vec![("B", String::from("bbb")), ("C", String::from("ccc"))])
+        );
+        assert_eq!(*removed.lock().unwrap(), [("B", RemovalCause::Capacity), ("C", RemovalCause::Capacity)]);
+        // Cache == [A, D], poids 9
+        assert_eq!(cache.weight(), 9);
+
+        // Un remplacement plus lourd retire les autres éléments, jamais l'élément remplacé
+        assert_eq!(
+            cache.put("A", String::from("aaaaa")),
+            PutResult::Replaced(String::from("aaa"), vec![("D", String::from("dddddd"))])
+        );
+        // Cache == [A], poids 5
+        assert_eq!(cache.len(), 1);
+        assert_eq!(cache.weight(), 5);
+
+        // Un élément plus lourd que tout le cache est refusé
+        let heavy = String::from("hhhhhhhhhhh");
+        assert_eq!(cache.put("H", heavy.clone()), PutResult::Rejected("H", heavy.clone()));
+        assert_eq!(cache.put("A", heavy.clone()), PutResult::Rejected("A", heavy.clone()));
+        // Comme avec l'API entry, "A" garde son ancienne valeur
+        assert_eq!(removed.lock().unwrap().last(), Some(&("D", RemovalCause::Capacity)));
+        assert_eq!(cache.peek("A"), Some(&String::from("aaaaa")));
+        assert_eq!(cache.weight(), 5);
+        cache.remove("A");
+
+        // L'API entry refuse aussi les éléments trop lourds
+        assert_eq!(cache.get_or_insert_with("B", || heavy.clone()), Err(heavy.clone()));
+        assert_eq!(cache.entry("B").or_insert(String::from("bb")), Ok(&mut String::from("bb")));
+        match cache.entry("B") {
+            Entry::Occupied(mut entry) => assert_eq!(entry.insert(heavy.clone()), Err(heavy)),
+            Entry::Vacant(_) => unreachable!(),
+        }
+        assert_eq!(cache.peek("B"), Some(&String::from("bb")));
+        assert_eq!(cache.weight(), 2);
+    }
+
+    #[test]
+    fn test_memory_limited_cache() {
+        assert_eq!(String::with_capacity(8).heap_size(), 8);
+        let strings = vec![String::from("abcd"), String::from("efgh")];
+        assert_eq!(strings.heap_size(), 2 * std::mem::size_of::<String>() + 8);
+        assert_eq!(Box::new(1u64).heap_size(), 8);
+        assert_eq!(Some((1u8, String::with_capacity(3))).heap_size(), 3);
+        let map: HashMap<u32, u32> = HashMap::with_capacity(4);
+        assert!(map.heap_size() >= 4 * 9);
+
+        let mut cache = Cache::with_memory_limit(10_000);
+        for key in 0..10u32 {
+            cache.put(key, vec![0u8; 1000]);
+        }
+        // Chaque élément pèse ses 1000 octets et la gestion interne du cache : tous ne tiennent pas
+        let entry = cache.weight() / cache.len() as u64;
+        assert!(entry > 1000);
+        assert!(cache.weight() <= 10_000);
+        assert!(!cache.contains(&0));
+        assert!(cache.contains(&9));
+
+        // Une valeur plus grande retire les plus anciennes
+        let len = cache.len();
+        cache.put(10, vec![0u8; 3000]);
+        assert!(cache.len() < len);
+        assert!(cache.weight() <= 10_000);
+
+        // Une valeur plus grande que tout le cache n'est pas conservée, même par l'API entry
+        assert_eq!(cache.get_or_insert_with(11, || vec![0u8; 20_000]).map_err(|value| value.len()), Err(20_000));
+        assert!(cache.weight() <= 10_000);
+
+        // L'estimation complète compte aussi la mémoire réservée par le cache
+        assert!(cache.memory_usage() as u64 >= cache.weight());
+
+        // ainsi que les échéances des durées de vie
+        let mut plain = Cache::new(4);
+        let mut expiring = Cache::new(4);
+        for key in 0..4u32 {
+            plain.put(key, key);
+            expiring.put_with_ttl(key, key, Duration::from_secs(60));
+        }
+        let expiration_size = std::mem::size_of::<(std::time::Instant, usize)>();
+        assert_eq!(expiring.memory_usage(), plain.memory_usage() + 4 * expiration_size);
+    }
+
+    #[test]
+    fn test_cache_ttl() {
+        let clock = MockClock::new();
+        let removed = Arc::new(Mutex::new(Vec::new()));
+        let mut cache = Cache::new(3);
+        cache.set_clock(clock.clone());
+        let log = Arc::clone(&removed);
+        cache.set_removal_listener(move |key, _value, cause| log.lock().unwrap().push((*key, cause)));
+
+        cache.put_with_ttl("A", 1, Duration::from_secs(60));
+        cache.put_with_ttl("B", 2, Duration::from_secs(10));
+        cache.put("C", 3);
+        clock.advance(Duration::from_secs(9));
+        assert_eq!(cache.peek("B"), Some(&2));
+
+        clock.advance(Duration::from_secs(1));
+        // Cache == [A, B, C], "B" a expiré
+        // Un élément expiré est absent mais n'est retiré que par une méthode qui modifie le cache
+        assert_eq!(cache.peek("B"), None);
+        assert!(!cache.contains("B"));
+        assert_eq!(cache.len(), 3);
+
+        // Le cache est plein : l'élément expiré est retiré avant le plus ancien
+        assert_eq!(cache.put("D", 4), PutResult::Inserted);
+        assert_eq!(*removed.lock().unwrap(), [("B", RemovalCause::Expired)]);
+        assert!(cache.contains("A"));
+        // Cache == [A, C, D]
+        cache.set_capacity(4);
+
+        // Une durée de vie par défaut s'applique aux éléments ajoutés ou remplacés sans durée explicite
+        cache.set_default_ttl(Some(Duration::from_secs(5)));
+        assert_eq!(cache.default_ttl(), Some(Duration::from_secs(5)));
+        assert_eq!(cache.put("C", 30), PutResult::Replaced(3, vec![]));
+        assert_eq!(cache.put_with_ttl("D", 40, Duration::from_secs(100)), PutResult::Replaced(4, vec![]));
+        assert_eq!(cache.entry("E").or_insert(5), Ok(&mut 5));
+        // Cache == [A, C, D, E] : "C" et "D" ont été remplacés
+        clock.advance(Duration::from_secs(5));
+        assert_eq!(cache.get("C"), None);
+        assert_eq!(cache.get("D"), Some(&40));
+        assert_eq!(cache.remove("E"), None);
+
+        // "A" expire 60 secondes après son ajout
+        cache.set_default_ttl(None);
+        clock.advance(Duration::from_secs(40));
+        assert!(cache.contains("A"));
+        clock.advance(Duration::from_secs(5));
+        assert_eq!(cache.remove_expired(), 1);
+        assert_eq!(cache.keys().copied().collect::<Vec<_>>(), ["D"]);
+
+        // Une durée de vie trop grande pour être représentée n'expire jamais
+        assert_eq!(cache.put_with_ttl("F", 6, Duration::MAX), PutResult::Inserted);
+        clock.advance(Duration::from_secs(3600));
+        assert_eq!(cache.get("F"), Some(&6));
+        assert_eq!(
+            removed.lock().unwrap()[1..],
+            [
+                ("C", RemovalCause::Replaced),
+                ("D", RemovalCause::Replaced),
+                ("C", RemovalCause::Expired),
+                ("E", RemovalCause::Expired),
+                ("A", RemovalCause::Expired),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_cache_time_to_idle() {
+        let clock = MockClock::new();
+        let mut cache = FifoCache::with_policy(3, Fifo);
+        cache.set_clock(clock.clone());
+        cache.extend([("A", 1), ("B", 2), ("C", 3)]);
+        cache.get("A");
+        // Sans expiration faute d'utilisation, FIFO ne modifie pas l'ordre : Cache == [A, B, C]
+        assert_eq!(cache.keys().rev().copied().collect::<Vec<_>>(), ["A", "B", "C"]);
+
+        // L'ordre des utilisations est tenu à part : FIFO garde son ordre
+        let idle = Duration::from_secs(60);
+        cache.set_time_to_idle(Some(idle));
+        assert_eq!(cache.time_to_idle(), Some(idle));
+        clock.advance(Duration::from_secs(30));
+        cache.get("A");
+        assert_eq!(cache.keys().rev().copied().collect::<Vec<_>>(), ["A", "B", "C"]);
+
+        // `peek` ne compte pas comme une utilisation par défaut
+        clock.advance(Duration::from_secs(20));
+        cache.peek("B");
+        clock.advance(Duration::from_secs(10));
+        assert_eq!(cache.peek("B"), None);
+        assert_eq!(cache.remove_expired(), 2);
+        // Cache == [A], utilisé il y a 30 secondes
+
+        // Avec `set_refresh_on_peek`, l'élément consulté est passé lors de la vérification
+        cache.set_refresh_on_peek(true);
+        cache.put("D", 4);
+        clock.advance(Duration::from_secs(20));
+        assert_eq!(cache.peek("A"), Some(&1));
+        clock.advance(Duration::from_secs(50));
+        assert_eq!(cache.remove_expired(), 1);
+        assert_eq!(cache.keys().copied().collect::<Vec<_>>(), ["A"]);
+
+        // Chaque utilisation repousse l'expiration
+        assert_eq!(cache.get("A"), Some(&1));
+        for _ in 0..5 {
+            clock.advance(Duration::from_secs(59));
+            assert_eq!(cache.get("A"), Some(&1));
+        }
+        clock.advance(idle);
+        assert_eq!(cache.get("A"), None);
+        assert!(cache.is_empty());
+
+        // FIFO retire toujours l'élément inséré le plus tôt
+        let mut fifo = FifoCache::with_default_policy(2);
+        fifo.set_time_to_idle(Some(Duration::from_secs(3600)));
+        fifo.extend([("A", 1), ("B", 2)]);
+        fifo.get("A");
+        assert_eq!(fifo.put("C", 3), PutResult::Evicted(vec![("A", 1)]));
+
+        // Une durée trop grande pour être représentée revient à ne jamais expirer
+        fifo.set_time_to_idle(Some(Duration::MAX));
+        fifo.put("D", 4);
+        assert_eq!(fifo.get("D"), Some(&4));
+        assert_eq!(fifo.remove_expired(), 0);
+
+        // LRU : l'ordre du cache est l'ordre des utilisations, il n'est pas tenu une seconde fois
+        let mut lru = Cache::new(3);
+        let mut plain = Cache::new(3);
+        lru.set_clock(clock.clone());
+        lru.set_time_to_idle(Some(idle));
+        lru.set_refresh_on_peek(true);
+        lru.extend([("A", 1), ("B", 2), ("C", 3)]);
+        plain.extend([("A", 1), ("B", 2), ("C", 3)]);
+        assert_eq!(lru.memory_usage(), plain.memory_usage());
+        clock.advance(Duration::from_secs(30));
+        lru.peek("A");
+        lru.get("C");
+        clock.advance(Duration::from_secs(40));
+        // "B" a expiré, "A" est passé sans que `peek` ne modifie l'ordre du cache
+        assert_eq!(lru.remove_expired(), 1);
+        assert_eq!(lru.keys().rev().copied().collect::<Vec<_>>(), ["A", "C"]);
+
+        // Les utilisations sont comptées avec l'horloge du cache, même en retard sur l'horloge du système
+        let late_clock = MockClock::new();
+        std::thread::sleep(Duration::from_millis(20));
+        let mut cache = Cache::new(2);
+        cache.set_time_to_idle(Some(Duration::from_millis(10)));
+        cache.set_clock(late_clock.clone());
+        cache.put("A", 1);
+        late_clock.advance(Duration::from_millis(15));
+        assert_eq!(cache.get("A"), None);
+    }
+
+    #[test]
+    fn test_cache_is_send_and_sync() {
+        fn assert_send_sync<T: Send + Sync>() {}
+        assert_send_sync::<Cache<String, String>>();
+        assert_send_sync::<LfuCache<String, String>>();
+        assert_send_sync::<ArcCache<String, String>>();
+        assert_send_sync::<SlruCache<String, String>>();
+        assert_send_sync::<WTinyLfuCache<String, String>>();
+        assert_send_sync::<ClockCache<String, String>>();
+        assert_send_sync::<SieveCache<String, String>>();
+        assert_send_sync::<S3FifoCache<String, String>>();
+        assert_send_sync::<LirsCache<String, String>>();
+        assert_send_sync::<FifoCache<String, String>>();
+        assert_send_sync::<MruCache<String, String>>();
+        assert_send_sync::<RandomCache<String, String>>();
+        assert_send_sync::<GdsfCache<String, String>>();
+
+        // Un cache avec listener, poids et horloge peut être utilisé depuis un autre thread,
+        // même si le listener n'est pas `Sync`
+        let removed = Arc::new(Mutex::new(Vec::new()));
+        let log = Arc::clone(&removed);
+        let count = Cell::new(0);
+        let clock = MockClock::new();
+        let mut cache = Cache::with_weigher(2, |_: &String, value: &u32| u64::from(*value));
+        cache.set_clock(clock.clone());
+        cache.set_removal_listener(move |key: &String, _: &u32, cause| {
+            count.set(count.get() + 1);
+            log.lock().unwrap().push((count.get(), key.clone(), cause));
+        });
+
+        let mut cache = std::thread::spawn(move || {
+            cache.put("A".to_string(), 1);
+            cache.put("B".to_string(), 2);
+            cache
+        })
+        .join()
+        .unwrap();
+        assert_eq!(*removed.lock().unwrap(), [(1, "A".to_string(), RemovalCause::Capacity)]);
+
+        // et lu depuis plusieurs threads à la fois
+        std::thread::scope(|scope| {
+            for _ in 0..2 {
+                scope.spawn(|| assert_eq!(cache.peek("B"), Some(&2)));
+            }
+        });
+        cache.remove("B");
+        assert_eq!(removed.lock().unwrap().last(), Some(&(2, "B".to_string(), RemovalCause::Explicit)));
+    }
+}
@@ -1,6 +1,5 @@
 use hashmap_cache::Cache;
-
-mod cache;
+use hashmap_cache::cache::trait_cache::TraitCache;
 
 fn main() {
     let mut cache = Cache::new(3);
@@ -12,4 +11,4 @@ fn main() {
     cache.get("B");
 
     dbg!(cache.get("B"));
-}
\ No newline at end of file
+}